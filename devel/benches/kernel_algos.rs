@@ -0,0 +1,137 @@
+//! This compares the portable, `const` addition and multiplication
+//! algorithms to the architecture-specific kernels, such as the `mulx`,
+//! `adcx` and `adox` kernels on `x86_64` and the flag-based carry chains
+//! on `aarch64`.
+
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use i256::math::{add, mul};
+use i256::ULimb;
+use input::*;
+
+type Limbs = [ULimb; 4];
+
+fn to_limbs_data(data: &[[u128; 4]]) -> Vec<(Limbs, Limbs)> {
+    to_u256_udata(data).iter().map(|x| (x.0.to_ne_limbs(), x.1.to_ne_limbs())).collect()
+}
+
+macro_rules! kernel_op {
+    ($($func:ident)::*) => {
+        |x: &(Limbs, Limbs)| $($func)::*(&x.0, &x.1)
+    };
+}
+
+macro_rules! kernel_group {
+    ($name:ident, $strategy:expr, $prefix:literal) => {
+        fn $name(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group("kernel");
+            group.measurement_time(Duration::from_secs(5));
+
+            let seed = fastrand::u64(..);
+            let mut rng = fastrand::Rng::with_seed(seed);
+            let u128_udata = u128::gen_n::<4>($strategy, &mut rng, DEFAULT_COUNT);
+            let limb_data = to_limbs_data(&u128_udata);
+
+            add_bench!(
+                group,
+                concat!($prefix, "::wrapping_add-portable"),
+                limb_data.iter(),
+                kernel_op!(add::wrapping_unsigned)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::overflowing_add-portable"),
+                limb_data.iter(),
+                kernel_op!(add::overflowing_unsigned)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::wrapping_mul-portable"),
+                limb_data.iter(),
+                kernel_op!(mul::wrapping_unsigned)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::wrapping_mul_limb-portable"),
+                limb_data.iter(),
+                |x: &(Limbs, Limbs)| mul::wrapping_limb(&x.0, x.1[0])
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::widening_mul-portable"),
+                limb_data.iter(),
+                kernel_op!(mul::widening)
+            );
+
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_endian = "little",
+                not(feature = "noasm"),
+                not(feature = "limb32")
+            ))]
+            {
+                use i256::math::aarch64;
+                add_bench!(
+                    group,
+                    concat!($prefix, "::wrapping_add-aarch64"),
+                    limb_data.iter(),
+                    kernel_op!(aarch64::wrapping_add)
+                );
+                add_bench!(
+                    group,
+                    concat!($prefix, "::overflowing_add-aarch64"),
+                    limb_data.iter(),
+                    kernel_op!(aarch64::overflowing_add)
+                );
+                add_bench!(
+                    group,
+                    concat!($prefix, "::wrapping_mul-aarch64"),
+                    limb_data.iter(),
+                    kernel_op!(aarch64::wrapping_mul)
+                );
+                add_bench!(
+                    group,
+                    concat!($prefix, "::widening_mul-aarch64"),
+                    limb_data.iter(),
+                    kernel_op!(aarch64::widening_mul)
+                );
+            }
+
+            #[cfg(all(target_arch = "x86_64", not(feature = "noasm"), not(feature = "limb32")))]
+            if is_x86_feature_detected!("bmi2") && is_x86_feature_detected!("adx") {
+                // SAFETY: Safe, since we've checked the CPU features.
+                add_bench!(
+                    group,
+                    concat!($prefix, "::wrapping_mul-mulx"),
+                    limb_data.iter(),
+                    |x: &(Limbs, Limbs)| unsafe { i256::math::mulx::wrapping(&x.0, &x.1) }
+                );
+                // SAFETY: Safe, since we've checked the CPU features.
+                add_bench!(
+                    group,
+                    concat!($prefix, "::widening_mul-mulx"),
+                    limb_data.iter(),
+                    |x: &(Limbs, Limbs)| unsafe { i256::math::mulx::widening(&x.0, &x.1) }
+                );
+                // SAFETY: Safe, since we've checked the CPU features.
+                add_bench!(
+                    group,
+                    concat!($prefix, "::wrapping_mul_limb-mulx"),
+                    limb_data.iter(),
+                    |x: &(Limbs, Limbs)| unsafe { i256::math::mulx::wrapping_limb(&x.0, x.1[0]) }
+                );
+            }
+        }
+    };
+}
+
+kernel_group!(kernel_uniform, RandomGen::Uniform, "uniform");
+kernel_group!(kernel_simple, RandomGen::Simple, "simple");
+kernel_group!(kernel_large, RandomGen::Large, "large");
+
+criterion_group!(kernel_benches, kernel_uniform, kernel_simple, kernel_large);
+criterion_main!(kernel_benches);
@@ -121,9 +121,128 @@ macro_rules! add_group {
     };
 }
 
+/// Convert little-endian 128-bit words to bytes.
+fn to_le_bytes<const N: usize>(words: &[u128]) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (chunk, word) in bytes.chunks_exact_mut(16).zip(words.iter()) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+// NOTE: The wider types use Karatsuba multiplication for the widening
+// product, so compare both the wrapping and widening multiplication.
+macro_rules! add_wide_group {
+    (
+        $name:ident,
+        $feature:literal,
+        $words:literal,
+        $bytes:literal,
+        $i256:ty,
+        $bnum:ty,
+        $crypto:ty,
+        $prefix:literal
+    ) => {
+        #[cfg(feature = $feature)]
+        fn $name(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group("mul");
+            group.measurement_time(Duration::from_secs(5));
+
+            let seed = fastrand::u64(..);
+            let mut rng = fastrand::Rng::with_seed(seed);
+
+            let data = u128::gen_n::<{ 2 * $words }>(RandomGen::Uniform, &mut rng, DEFAULT_COUNT);
+            let split = |x: &[u128; 2 * $words]| {
+                let lo: [u8; $bytes] = to_le_bytes(&x[..$words]);
+                let hi: [u8; $bytes] = to_le_bytes(&x[$words..]);
+                (lo, hi)
+            };
+            let bnum_data: Vec<($bnum, $bnum)> = data
+                .iter()
+                .map(split)
+                .map(|(x, y)| {
+                    (<$bnum>::from_le_slice(&x).unwrap(), <$bnum>::from_le_slice(&y).unwrap())
+                })
+                .collect();
+            let crypto_data: Vec<($crypto, $crypto)> = data
+                .iter()
+                .map(split)
+                .map(|(x, y)| (<$crypto>::from_le_slice(&x), <$crypto>::from_le_slice(&y)))
+                .collect();
+            let i256_data: Vec<($i256, $i256)> = data
+                .iter()
+                .map(split)
+                .map(|(x, y)| (<$i256>::from_le_bytes(x), <$i256>::from_le_bytes(y)))
+                .collect();
+
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-bnum"),
+                bnum_data.iter(),
+                bench_op!(wrapping_mul, $bnum)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-crypto"),
+                crypto_data.iter(),
+                bench_op!(@ref wrapping_mul, $crypto)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-i256"),
+                i256_data.iter(),
+                bench_op!(wrapping_mul, $i256)
+            );
+
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-widening-bnum"),
+                bnum_data.iter(),
+                bench_op!(widening_mul, $bnum)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-widening-crypto"),
+                crypto_data.iter(),
+                bench_op!(@ref mul_wide, $crypto)
+            );
+            add_bench!(
+                group,
+                concat!($prefix, "::unsigned-widening-i256"),
+                i256_data.iter(),
+                bench_op!(widening_mul, $i256)
+            );
+        }
+
+        #[cfg(not(feature = $feature))]
+        fn $name(_: &mut Criterion) {}
+    };
+}
+
 add_group!(mul_uniform, RandomGen::Uniform, "uniform");
 add_group!(mul_simple, RandomGen::Simple, "simple");
 add_group!(mul_large, RandomGen::Large, "large");
 
-criterion_group!(mul_random_benches, mul_uniform, mul_simple, mul_large);
+add_wide_group!(
+    mul_512,
+    "i512",
+    4,
+    64,
+    i256::U512,
+    bnum::types::U512,
+    crypto_bigint::U512,
+    "uniform-512"
+);
+add_wide_group!(
+    mul_1024,
+    "i1024",
+    8,
+    128,
+    i256::U1024,
+    bnum::types::U1024,
+    crypto_bigint::U1024,
+    "uniform-1024"
+);
+
+criterion_group!(mul_random_benches, mul_uniform, mul_simple, mul_large, mul_512, mul_1024);
 criterion_main!(mul_random_benches);
@@ -1,8 +1,19 @@
 #[macro_use]
 mod util;
 
+use bnum::cast::As;
+use bnum::types::{I512, U512};
 use quickcheck::quickcheck;
 
+struct Ed25519;
+
+impl i256::Modulus for Ed25519 {
+    const MODULUS: i256::u256 =
+        i256::u256::MAX.wrapping_shr(1).wrapping_sub(i256::u256::from_u8(18));
+}
+
+type Fe = i256::Fp<Ed25519>;
+
 quickcheck! {
     fn u256_wrapping_add_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128) -> bool {
         unsigned_op_equal!(wrap x0, x1, y0, y1, wrapping_add)
@@ -429,6 +440,426 @@ quickcheck! {
         expected == actual
     }
 
+    fn u256_to_float_quickcheck(x0: u128, x1: u128, y: u32) -> bool {
+        // NOTE: Shift to ensure we test values at every magnitude.
+        let bx = util::to_ubnum(x0, x1) >> (y % 256);
+        let ux = util::to_u256(x0, x1) >> (y % 256);
+        bx.as_::<f64>() == ux.to_f64() && bx.as_::<f32>() == ux.to_f32()
+    }
+
+    fn u256_from_f64_quickcheck(x: u64) -> bool {
+        // NOTE: bnum incorrectly rounds fractional values, so use the
+        // native casts for anything that might have a fractional component.
+        let f = f64::from_bits(x);
+        let expected = match f < 2.0f64.powi(127) {
+            true => i256::u256::from_u128(f as u128).to_le_bytes(),
+            false => f.as_::<util::Bu256>().to_le_bytes(),
+        };
+        let in_range = f > -1.0 && f < 2.0f64.powi(256);
+        let checked = i256::u256::checked_from_f64(f).map(|v| v.to_le_bytes());
+        let saturating = i256::u256::saturating_from_f64(f).to_le_bytes();
+        checked == in_range.then_some(expected) && saturating == expected
+    }
+
+    fn u256_from_f32_quickcheck(x: u32) -> bool {
+        // NOTE: Widening to `f64` is exact, and bnum's `f32` casts round.
+        let f = f32::from_bits(x);
+        let checked = i256::u256::checked_from_f32(f) == i256::u256::checked_from_f64(f as f64);
+        let saturating = i256::u256::saturating_from_f32(f) == i256::u256::saturating_from_f64(f as f64);
+        let wrapping = i256::u256::wrapping_from_f32(f) == i256::u256::wrapping_from_f64(f as f64);
+        checked && saturating && wrapping
+    }
+
+    fn u256_wrapping_from_f64_quickcheck(x: u64) -> bool {
+        let f = f64::from_bits(x);
+        let value = i256::u256::wrapping_from_f64(f);
+        match i256::u256::checked_from_f64(f.abs()) {
+            Some(v) if f.is_sign_negative() => value == v.wrapping_neg(),
+            Some(v) => value == v,
+            // NOTE: Any out-of-range value is a multiple of `2^(256 - 53)`.
+            None if f.is_finite() => value.trailing_zeros() >= 256 - 53,
+            None => value == i256::u256::MIN,
+        }
+    }
+
+    fn u256_isqrt_quickcheck(x0: u128, x1: u128, y: u32) -> bool {
+        // NOTE: Shift to ensure we test values at every magnitude.
+        let x = util::to_u256(x0, x1) >> (y % 256);
+        let root = x.isqrt();
+        let (lo, lo_hi) = root.widening_mul(root);
+        let next = root + i256::u256::from_u8(1);
+        let (hi, hi_hi) = next.widening_mul(next);
+        lo_hi == i256::u256::MIN && lo <= x && (hi_hi != i256::u256::MIN || x < hi)
+    }
+
+    fn u256_iroot_quickcheck(x0: u128, x1: u128, n: u32) -> bool {
+        let x = util::to_u256(x0, x1);
+        let n = n % 300 + 1;
+        let root = x.iroot(n);
+        let next = root.checked_add(i256::u256::from_u8(1)).and_then(|v| v.checked_pow(n));
+        root.checked_pow(n).map_or(false, |v| v <= x) && next.map_or(true, |v| v > x)
+    }
+
+    fn u256_is_perfect_power_quickcheck(x: u64, n: u32) -> bool {
+        let base = i256::u256::from_u64(x);
+        let n = n % 256 + 2;
+        match base.checked_pow(n) {
+            Some(power) if x > 1 => match power.is_perfect_power() {
+                Some((b, e)) => b.pow(e) == power && e % n == 0,
+                None => false,
+            },
+            _ => true,
+        }
+    }
+
+    fn u256_add_mod_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0, m1);
+        if m == i256::u256::MIN {
+            return true;
+        }
+        let bm = util::to_ubnum(m0, m1).as_::<U512>();
+        let bx = util::to_ubnum(x0, x1).as_::<U512>();
+        let by = util::to_ubnum(y0, y1).as_::<U512>();
+        let actual = util::to_u256(x0, x1).add_mod(util::to_u256(y0, y1), m);
+        actual.to_le_bytes() == ((bx + by) % bm).as_::<util::Bu256>().to_le_bytes()
+    }
+
+    fn u256_sub_mod_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0, m1);
+        if m == i256::u256::MIN {
+            return true;
+        }
+        let bm = util::to_ubnum(m0, m1).as_::<U512>();
+        let bx = util::to_ubnum(x0, x1).as_::<U512>();
+        let by = util::to_ubnum(y0, y1).as_::<U512>();
+        let actual = util::to_u256(x0, x1).sub_mod(util::to_u256(y0, y1), m);
+        let expected = (bx % bm + bm - by % bm) % bm;
+        actual.to_le_bytes() == expected.as_::<util::Bu256>().to_le_bytes()
+    }
+
+    fn u256_mul_mod_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0, m1);
+        if m == i256::u256::MIN {
+            return true;
+        }
+        let bm = util::to_ubnum(m0, m1).as_::<U512>();
+        let bx = util::to_ubnum(x0, x1).as_::<U512>();
+        let by = util::to_ubnum(y0, y1).as_::<U512>();
+        let actual = util::to_u256(x0, x1).mul_mod(util::to_u256(y0, y1), m);
+        actual.to_le_bytes() == ((bx * by) % bm).as_::<util::Bu256>().to_le_bytes()
+    }
+
+    fn u256_mul_div_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, c0: u128, c1: u128) -> bool {
+        let c = util::to_u256(c0, c1);
+        if c == i256::u256::MIN {
+            return true;
+        }
+        let bc = util::to_ubnum(c0, c1).as_::<U512>();
+        let bx = util::to_ubnum(x0, x1).as_::<U512>();
+        let by = util::to_ubnum(y0, y1).as_::<U512>();
+        let (quo, rem) = (bx * by / bc, bx * by % bc);
+        let actual = util::to_u256(x0, x1).checked_mul_div_rem(util::to_u256(y0, y1), c);
+        match actual {
+            Some((q, r)) => {
+                q.to_le_bytes() == quo.as_::<util::Bu256>().to_le_bytes()
+                    && r.to_le_bytes() == rem.as_::<util::Bu256>().to_le_bytes()
+            },
+            None => quo > util::Bu256::MAX.as_::<U512>(),
+        }
+    }
+
+    fn i256_mul_div_quickcheck(x0: u128, x1: i128, y0: u128, y1: i128, c0: u128, c1: i128) -> bool {
+        let c = util::to_i256(c0, c1);
+        if c == i256::i256::from_u8(0) {
+            return true;
+        }
+        let bc = util::to_ibnum(c0, c1).as_::<I512>();
+        let bx = util::to_ibnum(x0, x1).as_::<I512>();
+        let by = util::to_ibnum(y0, y1).as_::<I512>();
+        let (quo, rem) = (bx * by / bc, bx * by % bc);
+        let actual = util::to_i256(x0, x1).checked_mul_div_rem(util::to_i256(y0, y1), c);
+        match actual {
+            Some((q, r)) => {
+                q.to_le_bytes() == quo.as_::<util::Bi256>().to_le_bytes()
+                    && r.to_le_bytes() == rem.as_::<util::Bi256>().to_le_bytes()
+            },
+            None => quo.as_::<util::Bi256>().as_::<I512>() != quo,
+        }
+    }
+
+    fn i256_widening_mul_quickcheck(x0: u128, x1: i128, y0: u128, y1: i128, c0: u128, c1: i128) -> bool {
+        let bx = util::to_ibnum(x0, x1).as_::<I512>();
+        let by = util::to_ibnum(y0, y1).as_::<I512>();
+        let bc = util::to_ibnum(c0, c1).as_::<I512>();
+        let x = util::to_i256(x0, x1);
+        let y = util::to_i256(y0, y1);
+        let c = util::to_i256(c0, c1);
+        let is_equal = |(lo, hi): (i256::u256, i256::i256), expected: I512| {
+            lo.to_le_bytes() == expected.as_::<util::Bu256>().to_le_bytes()
+                && hi.to_le_bytes() == (expected >> 256u32).as_::<util::Bi256>().to_le_bytes()
+        };
+        is_equal(x.widening_mul(y), bx * by) && is_equal(x.carrying_mul(y, c), bx * by + bc)
+    }
+
+    fn u256_pow_mod_quickcheck(x: u64, y: u64, m: u64) -> bool {
+        if m == 0 {
+            return true;
+        }
+        let m128 = m as u128;
+        let mut expected = 1 % m128;
+        let mut base = x as u128 % m128;
+        let mut exp = y;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                expected = expected * base % m128;
+            }
+            base = base * base % m128;
+            exp >>= 1;
+        }
+        let actual = i256::u256::from_u64(x).pow_mod(i256::u256::from_u64(y), i256::u256::from_u64(m));
+        actual == i256::u256::from_u128(expected)
+    }
+
+    fn u256_pow_mod_identity_quickcheck(x0: u128, x1: u128, a: u32, b: u32, m0: u128, m1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        let m = util::to_u256(m0, m1);
+        let (a, b) = (i256::u256::from_u32(a), i256::u256::from_u32(b));
+        m == i256::u256::MIN
+            || x.pow_mod(a + b, m) == x.pow_mod(a, m).mul_mod(x.pow_mod(b, m), m)
+    }
+
+    fn u256_inv_mod_quickcheck(x0: u128, x1: u128, m0: u128, m1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        let m = util::to_u256(m0, m1);
+        let one = i256::u256::from_u8(1);
+        match (m == i256::u256::MIN, m == one) {
+            (true, _) => true,
+            (_, true) => x.inv_mod(m) == Some(i256::u256::MIN),
+            _ => match x.inv_mod(m) {
+                Some(inv) => inv < m && x.mul_mod(inv, m) == one,
+                None => {
+                    let (mut a, mut b) = (m, x);
+                    while b != i256::u256::MIN {
+                        (a, b) = (b, a % b);
+                    }
+                    a != one
+                },
+            },
+        }
+    }
+
+    fn u256_montgomery_mul_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0 | 1, m1);
+        let ctx = i256::Montgomery::<i256::u256>::new(m);
+        let x = util::to_u256(x0, x1);
+        let y = util::to_u256(y0, y1);
+        let product = ctx.mul(ctx.to_mont(x), ctx.to_mont(y));
+        ctx.from_mont(product) == x.mul_mod(y, m) && ctx.from_mont(ctx.to_mont(x)) == x % m
+    }
+
+    fn u256_montgomery_pow_quickcheck(x0: u128, x1: u128, e: u32, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0 | 1, m1);
+        let ctx = i256::Montgomery::<i256::u256>::new(m);
+        let x = util::to_u256(x0, x1);
+        let mut expected = i256::u256::from_u8(1) % m;
+        for _ in 0..e % 64 {
+            expected = expected.mul_mod(x, m);
+        }
+        ctx.from_mont(ctx.pow(ctx.to_mont(x), i256::u256::from_u32(e % 64))) == expected
+    }
+
+    fn u256_square_quickcheck(x0: u128, x1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        x.widening_square() == x.widening_mul(x)
+            && x.overflowing_square() == x.overflowing_mul(x)
+            && x.wrapping_square() == x.wrapping_mul(x)
+    }
+
+    fn i256_square_quickcheck(x0: u128, x1: i128) -> bool {
+        let x = util::to_i256(x0, x1);
+        x.overflowing_square() == x.overflowing_mul(x) && x.wrapping_square() == x.wrapping_mul(x)
+    }
+
+    fn u256_div_const_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        let y = util::to_u256(y0, y1);
+        y == i256::u256::MIN || x.div_rem_const(y) == x.wrapping_div_rem(y)
+    }
+
+    fn u256_divider_quickcheck(x0: u128, x1: u128, d0: u128, d1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        let d = util::to_u256(d0, d1);
+        if d == i256::u256::MIN {
+            return true;
+        }
+        let divider = i256::Divider::<i256::u256>::new(d);
+        let limb = i256::Divider::<i256::u256, i256::ULimb>::checked_new(d0 as i256::ULimb);
+        divider.div_rem(x) == x.wrapping_div_rem(d)
+            && limb.map_or(d0 as i256::ULimb == 0, |l| l.div_rem(x) == x.wrapping_div_rem_ulimb(d0 as i256::ULimb))
+    }
+
+    fn u256_barrett_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, m0: u128, m1: u128) -> bool {
+        let m = util::to_u256(m0, m1);
+        if m == i256::u256::MIN {
+            return true;
+        }
+        let ctx = i256::Barrett::<i256::u256>::new(m);
+        let bm = util::to_ubnum(m0, m1).as_::<U512>();
+        let bx = util::to_ubnum(x0, x1).as_::<U512>();
+        let by = util::to_ubnum(y0, y1).as_::<U512>();
+        let wide = (by << 256u32) | bx;
+        let reduced = ctx.reduce_wide(util::to_u256(x0, x1), util::to_u256(y0, y1));
+        let product = ctx.mul(util::to_u256(x0, x1), util::to_u256(y0, y1));
+        reduced.to_le_bytes() == (wide % bm).as_::<util::Bu256>().to_le_bytes()
+            && product.to_le_bytes() == ((bx * by) % bm).as_::<util::Bu256>().to_le_bytes()
+    }
+
+    fn u256_gcd_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128, k: u64) -> bool {
+        let k = i256::u256::from_u64(k);
+        let x = util::to_u256(x0, x1).wrapping_mul(k);
+        let y = util::to_u256(y0, y1).wrapping_mul(k);
+        let gcd = x.gcd(y);
+        let (egcd, a, b) = x.extended_gcd(y);
+        let signed = |v: i256::i256| util::Bi256::from_le_slice(&v.to_le_bytes()).unwrap().as_::<I512>();
+        let unsigned = |v: i256::u256| util::Bu256::from_le_slice(&v.to_le_bytes()).unwrap().as_::<I512>();
+        let bezout = signed(a) * unsigned(x) + signed(b) * unsigned(y);
+        let zero = i256::u256::MIN;
+        let divides = gcd == zero || (x % gcd == zero && y % gcd == zero);
+        let coprime = gcd == zero || (x / gcd).gcd(y / gcd) == i256::u256::from_u8(1);
+        let lcm = x.checked_lcm(y).map_or(true, |lcm| gcd == zero || lcm == x / gcd * y);
+        egcd == gcd && bezout == unsigned(gcd) && divides && coprime && lcm
+    }
+
+    fn u256_crt_quickcheck(r0: u64, r1: u64, r2: u64, m0: u32, m1: u32, m2: u32) -> bool {
+        let system = [(r0, m0 as u64), (r1, m1 as u64), (r2, m2 as u64)];
+        let residues = system.map(|(r, _)| i256::u256::from_u64(r));
+        let moduli = system.map(|(_, m)| i256::u256::from_u64(m));
+        // NOTE: The LCM cannot overflow, so a solution exists if and only if
+        // every pair of congruences is consistent.
+        let gcd = |mut a: u64, mut b: u64| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        let solvable = system.iter().all(|&(ri, mi)| {
+            mi != 0 && system.iter().all(|&(rj, mj)| {
+                let g = gcd(mi, mj);
+                mj == 0 || ri % g == rj % g
+            })
+        });
+        match i256::u256::crt(&residues, &moduli) {
+            Some(x) => solvable && system.iter().all(|&(r, m)| x % i256::u256::from_u64(m) == i256::u256::from_u64(r % m)),
+            None => !solvable,
+        }
+    }
+
+    fn u256_sqrt_mod_quickcheck(x0: u128, x1: u128) -> bool {
+        let x = util::to_u256(x0, x1);
+        // 2^255 - 19, secp256k1 and NIST P-224
+        let p224 = i256::u256::from_u8(1).wrapping_shl(224).wrapping_sub(i256::u256::from_u8(1).wrapping_shl(96));
+        let moduli = [
+            i256::u256::MAX.wrapping_shr(1).wrapping_sub(i256::u256::from_u8(18)),
+            i256::u256::MAX.wrapping_sub(i256::u256::from_u64(0x1_0000_03d0)),
+            p224.wrapping_add(i256::u256::from_u8(1)),
+        ];
+        moduli.iter().all(|&p| {
+            let square = x.mul_mod(x, p);
+            let root = square.sqrt_mod(p);
+            let expected = x.wrapping_rem(p);
+            root.map_or(false, |r| r == expected || r == p.wrapping_sub(expected).wrapping_rem(p))
+                && x.sqrt_mod(p).is_some() == (x.legendre(p) != -1)
+        })
+    }
+
+    fn u256_binomial_quickcheck(n: u8, k: u8) -> bool {
+        // NOTE: `C(n, k) < 2^96` for `n < 100`, so no intermediate value overflows a `u128`.
+        let (n, k) = (n as u32 % 100, k as u32 % 100);
+        let mut expected = 1u128;
+        for i in 0..k.min(n) {
+            expected = expected * (n - i) as u128 / (i + 1) as u128;
+        }
+        let expected = if k > n { 0 } else { expected };
+        let expected = i256::u256::from_u128(expected);
+        // NOTE: `k!` overflows for `k >= 58`, and the falling factorial can
+        // overflow for large `n`, so only check the identity if both fit.
+        let falling = i256::u256::checked_falling_factorial(n, k);
+        let factorial = i256::u256::checked_factorial(k);
+        let is_identity = match falling.zip(factorial) {
+            Some((f, k)) => f.wrapping_div(k) == expected,
+            None => true,
+        };
+        i256::u256::checked_binomial(n, k) == Some(expected) && is_identity
+    }
+
+    fn u256_is_prime_quickcheck(x: u64) -> bool {
+        // NOTE: These bases are deterministic for all 64-bit integers.
+        let is_prime = |n: u64| {
+            if n < 2 || n % 2 == 0 {
+                return n == 2;
+            }
+            let pow = |mut base: u128, mut exp: u64| {
+                let mut result = 1u128;
+                while exp != 0 {
+                    if exp & 1 == 1 {
+                        result = result * base % n as u128;
+                    }
+                    base = base * base % n as u128;
+                    exp >>= 1;
+                }
+                result
+            };
+            let s = (n - 1).trailing_zeros();
+            let d = (n - 1) >> s;
+            [2u64, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37].iter().all(|&a| {
+                if a % n == 0 {
+                    return true;
+                }
+                let mut x = pow(a as u128, d);
+                (0..s).any(|_| {
+                    let found = x == n as u128 - 1;
+                    x = x * x % n as u128;
+                    found
+                }) || pow(a as u128, d) == 1
+            })
+        };
+        let n = i256::u256::from_u64(x);
+        let next = n.next_prime().unwrap();
+        let prev = n.prev_prime();
+        // NOTE: There are no primes between `prev` and `n`, or `n` and `next`.
+        n.is_prime() == is_prime(x)
+            && next > n
+            && next.is_prime()
+            && next.prev_prime().map_or(x < 2, |p| p <= n)
+            && prev.map_or(x <= 2, |p| p < n && p.is_prime() && p.next_prime().map_or(false, |p| p >= n))
+    }
+
+    fn u256_factor_quickcheck(x: u64, y: u32) -> bool {
+        let n = i256::u256::from_u64(x.max(1)).wrapping_mul(i256::u256::from_u32(y.max(1)));
+        let factors = n.factor();
+        let product = factors.iter().fold(i256::u256::from_u8(1), |acc, &(p, e)| {
+            acc.wrapping_mul(p.wrapping_pow(e))
+        });
+        product == n
+            && factors.iter().all(|&(p, e)| p.is_prime() && e != 0)
+            && factors.windows(2).all(|w| w[0].0 < w[1].0)
+    }
+
+    fn fp_ops_quickcheck(x0: u128, x1: u128, y0: u128, y1: u128) -> bool {
+        let p = Fe::MODULUS;
+        let x = util::to_u256(x0, x1);
+        let y = util::to_u256(y0, y1);
+        let (fx, fy) = (Fe::new(x), Fe::new(y));
+        let quotient = fx.checked_div(fy).map(Fe::to_u256);
+        (fx + fy).to_u256() == x.add_mod(y, p)
+            && (fx - fy).to_u256() == x.sub_mod(y, p)
+            && (fx * fy).to_u256() == x.mul_mod(y, p)
+            && (-fx).to_u256() == i256::u256::MIN.sub_mod(x, p)
+            && quotient == y.inv_mod(p).map(|inv| x.mul_mod(inv, p))
+            && fx.pow(y).to_u256() == x.pow_mod(y, p)
+            && fx.square().sqrt().map_or(false, |r| r == fx || r == -fx)
+    }
+
     fn i256_wrapping_add_quickcheck(x0: u128, x1: i128, y0: u128, y1: i128) -> bool {
         signed_op_equal!(wrap x0, x1, y0, y1, wrapping_add)
     }
@@ -598,6 +1029,66 @@ quickcheck! {
         signed_op_equal!(scalar x0, x1, ilog2)
     }
 
+    fn i256_to_float_quickcheck(x0: u128, x1: i128, y: u32) -> bool {
+        // NOTE: Shift to ensure we test values at every magnitude.
+        let bx = util::to_ibnum(x0, x1) >> (y % 256);
+        let ix = util::to_i256(x0, x1) >> (y % 256);
+        bx.as_::<f64>() == ix.to_f64() && bx.as_::<f32>() == ix.to_f32()
+    }
+
+    fn i256_from_f64_quickcheck(x: u64) -> bool {
+        // NOTE: bnum incorrectly rounds fractional values, so use the
+        // native casts for anything that might have a fractional component.
+        let f = f64::from_bits(x);
+        let expected = match f.abs() < 2.0f64.powi(126) {
+            true => i256::i256::from_i128(f as i128).to_le_bytes(),
+            false => f.as_::<util::Bi256>().to_le_bytes(),
+        };
+        let in_range = f >= -(2.0f64.powi(255)) && f < 2.0f64.powi(255);
+        let checked = i256::i256::checked_from_f64(f).map(|v| v.to_le_bytes());
+        let saturating = i256::i256::saturating_from_f64(f).to_le_bytes();
+        checked == in_range.then_some(expected) && saturating == expected
+    }
+
+    fn i256_from_f32_quickcheck(x: u32) -> bool {
+        // NOTE: Widening to `f64` is exact, and bnum's `f32` casts round.
+        let f = f32::from_bits(x);
+        let checked = i256::i256::checked_from_f32(f) == i256::i256::checked_from_f64(f as f64);
+        let saturating = i256::i256::saturating_from_f32(f) == i256::i256::saturating_from_f64(f as f64);
+        let wrapping = i256::i256::wrapping_from_f32(f) == i256::i256::wrapping_from_f64(f as f64);
+        checked && saturating && wrapping
+    }
+
+    fn i256_wrapping_from_f64_quickcheck(x: u64) -> bool {
+        let f = f64::from_bits(x);
+        let value = i256::i256::wrapping_from_f64(f);
+        let magnitude = i256::u256::wrapping_from_f64(f.abs()).as_signed();
+        match f.is_sign_negative() {
+            true => value == magnitude.wrapping_neg(),
+            false => value == magnitude,
+        }
+    }
+
+    fn i256_checked_isqrt_quickcheck(x0: u128, x1: i128) -> bool {
+        let x = util::to_i256(x0, x1);
+        match x.checked_isqrt() {
+            Some(root) => !x.is_negative() && root.as_unsigned() == x.as_unsigned().isqrt(),
+            None => x.is_negative(),
+        }
+    }
+
+    fn i256_checked_iroot_quickcheck(x0: u128, x1: i128, n: u32) -> bool {
+        let x = util::to_i256(x0, x1);
+        let n = n % 300;
+        match x.checked_iroot(n) {
+            Some(root) if x.is_negative() => {
+                n % 2 == 1 && root.unsigned_abs() == x.unsigned_abs().iroot(n) && root <= i256::i256::from_u8(0)
+            },
+            Some(root) => root.as_unsigned() == x.as_unsigned().iroot(n),
+            None => n == 0 || (n % 2 == 0 && x.is_negative()),
+        }
+    }
+
     fn i256_checked_ilog2_quickcheck(x0: u128, x1: i128) -> bool {
         signed_op_equal!(x0, x1, checked_ilog2, |x, y| x == y)
     }
@@ -1385,6 +1876,108 @@ quickcheck! {
     }
 }
 
+#[test]
+fn isqrt_tests() {
+    // NOTE: These are generated from `etc/isqrt.py`, and validated
+    // with `math.isqrt`.
+    let cases = [
+        ("0x0", "0x0"),
+        ("0x1", "0x1"),
+        ("0xffffffffffffffff", "0xffffffff"),
+        ("0x10000000000000000", "0x100000000"),
+        (
+            "0x750b79840a35e888cea8684b60033cd65db233956ea88f4b4f72fd3f7d254db8",
+            "0xad197ffb686a9f3b306b6a1e2cfbe8ef",
+        ),
+        (
+            "0xaacdabbb49c9c6072c54a01283037cadfde8ec5e3e1544596ebbec4cc598e827",
+            "0xd11b59e9762dbd6dac8133b597c554a0",
+        ),
+        (
+            "0xd2aeeaf914c7d3fd9a1ac067541b8ee6f0969fe15284b2bf8e56916a518a4444",
+            "0xe83d2a2117371c98c54c4a701cda3eeb",
+        ),
+        (
+            "0xf09b30460cce5b3445fff12fb4d7a20d294b97d08e7981664997082c8b7e20bf",
+            "0xf82f0c31d7b3d759d5cbdfe9b09168ab",
+        ),
+        (
+            "0xfde9c7e9675be2b6da6f2974beeb65d108c25300fecf0c9277eeb71d894a472b",
+            "0xfef45808cdc251e79ec73a2331515b79",
+        ),
+        (
+            "0xd7a7836fcaf25f54c66f555c240a97759009eb69b50f9ca5376f3052c49915f5",
+            "0xeaf67982362a9fb6f5a6519ddc978161",
+        ),
+        (
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0xffffffffffffffffffffffffffffffff",
+        ),
+    ];
+    for (x, root) in cases {
+        let x = i256::u256::from_str_radix(&x[2..], 16).unwrap();
+        let root = i256::u256::from_str_radix(&root[2..], 16).unwrap();
+        assert_eq!(x.isqrt(), root);
+        if x <= i256::i256::MAX.as_unsigned() {
+            assert_eq!(x.as_signed().isqrt(), root.as_signed());
+        }
+    }
+    assert_eq!(i256::i256::from_i8(-1).checked_isqrt(), None);
+    assert_eq!(i256::i256::MIN.checked_isqrt(), None);
+}
+
+#[test]
+fn iroot_tests() {
+    // NOTE: These are validated with `sympy.integer_nthroot`.
+    let x = i256::u256::from_str_radix(
+        "795b929e9a9a80fdea7b5bf55eb561a4216363698b529b4a97b750923ceb3ffd",
+        16,
+    )
+    .unwrap();
+    assert_eq!(x.icbrt(), i256::u256::from_str_radix("1f6fc5fdff8e428b518a4e", 16).unwrap());
+    assert_eq!(x.iroot(5), i256::u256::from_str_radix("7ea4a09213726", 16).unwrap());
+    assert_eq!(x.iroot(17), i256::u256::from_u32(0x7f99));
+    assert_eq!(x.iroot(255), i256::u256::from_u8(1));
+    assert_eq!(i256::u256::MAX.iroot(255), i256::u256::from_u8(2));
+    assert_eq!(i256::u256::MAX.iroot(256), i256::u256::from_u8(1));
+    assert_eq!(x.checked_iroot(0), None);
+
+    assert_eq!(i256::i256::from_i8(-27).icbrt(), i256::i256::from_i8(-3));
+    assert_eq!(i256::i256::from_i8(-26).icbrt(), i256::i256::from_i8(-2));
+    assert_eq!(i256::i256::MIN.iroot(255), i256::i256::from_i8(-2));
+    assert_eq!(i256::i256::MIN.iroot(1), i256::i256::MIN);
+    assert_eq!(i256::i256::from_i8(-27).checked_iroot(2), None);
+}
+
+#[test]
+fn is_perfect_power_tests() {
+    // NOTE: These are validated with `sympy.perfect_power`.
+    let u = |x: u64, n: u32| i256::u256::from_u64(x).pow(n);
+    assert_eq!(u(2, 255).is_perfect_power(), Some((i256::u256::from_u8(2), 255)));
+    assert_eq!(u(3, 160).is_perfect_power(), Some((i256::u256::from_u8(3), 160)));
+    assert_eq!(u(6, 98).is_perfect_power(), Some((i256::u256::from_u8(6), 98)));
+    assert_eq!(u(12, 64).is_perfect_power(), Some((i256::u256::from_u8(12), 64)));
+    assert_eq!(
+        u((1 << 61) - 1, 4).is_perfect_power(),
+        Some((i256::u256::from_u64((1 << 61) - 1), 4))
+    );
+    assert_eq!((u(12, 64) + i256::u256::from_u8(1)).is_perfect_power(), None);
+    assert_eq!(i256::u256::from_u8(0).is_perfect_power(), Some((i256::u256::from_u8(0), 2)));
+    assert_eq!(i256::u256::from_u8(1).is_perfect_power(), Some((i256::u256::from_u8(1), 2)));
+    assert_eq!(i256::u256::from_u8(2).is_perfect_power(), None);
+    assert_eq!(i256::u256::MAX.is_perfect_power(), None);
+
+    assert_eq!(i256::i256::MIN.is_perfect_power(), Some((i256::i256::from_i8(-2), 255)));
+    assert_eq!(i256::i256::from_i8(-1).is_perfect_power(), Some((i256::i256::from_i8(-1), 3)));
+    assert_eq!(i256::i256::from_i8(-4).is_perfect_power(), None);
+    assert_eq!(i256::i256::from_i16(-729).is_perfect_power(), Some((i256::i256::from_i8(-9), 3)));
+    assert_eq!(
+        i256::i256::from_i32(-(1 << 12)).is_perfect_power(),
+        Some((i256::i256::from_i8(-16), 3))
+    );
+    assert_eq!(i256::i256::from_i16(729).is_perfect_power(), Some((i256::i256::from_i8(3), 6)));
+}
+
 #[test]
 #[cfg(all(not(feature = "limb32"), target_pointer_width = "64"))]
 fn overflowing_sub_tests() {
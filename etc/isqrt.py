@@ -0,0 +1,87 @@
+'''
+    isqrt
+    =====
+
+    A Python implementation of our integer square root.
+
+    This seeds the root from the high 64 bits of the value, and then
+    refines it with Newton's method, which converges quadratically from
+    above. This mirrors the implementation of `isqrt` for our unsigned
+    types, and validates it against `math.isqrt` for arbitrary bit widths.
+
+    This is obviously not meant for performance but to validate
+    our algorithms, and to generate test vectors.
+'''
+
+import math
+import random
+
+
+def isqrt_u64(n: int) -> int:
+    '''Calculate the integer square root of a 64-bit value.'''
+
+    assert 0 <= n < 2**64
+    if n < 2:
+        return n
+
+    x = 1 << ((n.bit_length() - 1) // 2 + 1)
+    while True:
+        y = (x + n // x) >> 1
+        if y >= x:
+            return x
+        x = y
+
+
+def isqrt(n: int, bits: int = 256) -> tuple[int, int]:
+    '''
+    Calculate the integer square root of a value with a fixed bit-width.
+
+    Returns the root and the number of Newton iterations required.
+    '''
+
+    assert 0 <= n < 2**bits
+    if n.bit_length() <= 64:
+        return (isqrt_u64(n), 0)
+
+    shift = (n.bit_length() - 64 + 1) // 2
+    x = (isqrt_u64(n >> (2 * shift)) + 1) << shift
+    # NOTE: The root must be able to hold the seed without overflow.
+    assert x < 2**(bits // 2 + 1)
+
+    iterations = 0
+    while True:
+        iterations += 1
+        y = (x + n // x) >> 1
+        if y >= x:
+            return (x, iterations)
+        x = y
+
+
+def validate(bits: int, count: int = 100000) -> int:
+    '''Validate our algorithm, returning the maximum number of iterations.'''
+
+    max_iterations = 0
+    for _ in range(count):
+        n = random.getrandbits(random.randint(1, bits))
+        root, iterations = isqrt(n, bits)
+        assert root == math.isqrt(n)
+        max_iterations = max(max_iterations, iterations)
+
+    for n in (2**bits - 1, 2**(bits - 1), 2**(bits - 1) - 1, 2**64, 2**64 - 1):
+        assert isqrt(n, bits)[0] == math.isqrt(n)
+
+    return max_iterations
+
+
+def print_vectors(bits: int = 256, count: int = 8) -> None:
+    '''Print test vectors as `(value, root)` hex pairs.'''
+
+    for _ in range(count):
+        n = random.getrandbits(bits)
+        print(f'("{n:#x}", "{math.isqrt(n):#x}"),')
+
+
+if __name__ == '__main__':
+    for bits in (256, 384, 512, 1024):
+        iterations = validate(bits, 10000)
+        print(f'{bits}-bit: max {iterations} iterations')
@@ -0,0 +1,198 @@
+//! Barrett reduction for a runtime modulus.
+//!
+//! This precomputes a scaled reciprocal of the modulus, so reducing a
+//! double-width value, such as the product of 2 integers, only requires
+//! multiplications, additions and shifts, rather than division. Unlike
+//! Montgomery multiplication, this works for any non-zero modulus and
+//! does not require any conversion of the values.
+
+/// A precomputed context for Barrett reduction.
+///
+/// This is created from any non-zero modulus `m`. The modulus is first
+/// normalized to `m' = m << s`, so its most-significant bit is set, and
+/// the reciprocal `floor((2^(2 * BITS) - 1) / m')` is precomputed. This
+/// reciprocal always has `BITS + 1` bits, where the most-significant bit
+/// is implicit. Reducing a double-width value by `m` then uses the
+/// identity `(x << s) mod m' == (x mod m) << s`.
+///
+/// ```rust
+/// use i256::{Barrett, U256};
+///
+/// let ctx = Barrett::<U256>::new(U256::from_u8(100));
+/// let (lo, hi) = U256::MAX.widening_mul(U256::MAX);
+/// assert_eq!(ctx.reduce_wide(lo, hi), U256::from_u8(25));
+/// assert_eq!(ctx.mul(U256::from_u8(12), U256::from_u8(34)), U256::from_u8(8));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Barrett<T> {
+    pub(crate) modulus: T,
+    pub(crate) normalized: T,
+    pub(crate) shift: u32,
+    pub(crate) mu: T,
+}
+
+#[rustfmt::skip]
+macro_rules! define {
+    (type => $t:ty $(,)?) => {
+        impl $crate::barrett::Barrett<$t> {
+            /// Create a new Barrett context for the `modulus`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `modulus` is zero.
+            #[inline]
+            #[must_use]
+            pub fn new(modulus: $t) -> Self {
+                match Self::checked_new(modulus) {
+                    Some(ctx) => ctx,
+                    None => panic!("Barrett modulus must be non-zero"),
+                }
+            }
+
+            /// Create a new Barrett context for the `modulus`, returning
+            /// `None` if the modulus is zero.
+            #[inline]
+            #[must_use]
+            pub fn checked_new(modulus: $t) -> Option<Self> {
+                if modulus.eq_const(<$t>::from_u8(0)) {
+                    return None;
+                }
+
+                // NOTE: Since `2^(BITS - 1) <= m' < 2^BITS`, the reciprocal
+                // is in the range `[2^BITS, 2^(BITS + 1))`, so we only store
+                // the low bits. Using `2^(2 * BITS) - 1` rather than
+                // `2^(2 * BITS)` only changes the result if `m'` is a power
+                // of two, which can underestimate the quotient by 1.
+                let shift = modulus.leading_zeros();
+                let normalized = modulus.wrapping_shl(shift);
+                let numerator = [$crate::ULimb::MAX; <$t>::LIMBS * 2];
+                let (quotient, _) = $crate::math::div::full(&numerator, &normalized.to_le_limbs());
+                let mut mu = [0; <$t>::LIMBS];
+                let mut i = 0;
+                while i < <$t>::LIMBS {
+                    mu[i] = quotient[i];
+                    i += 1;
+                }
+
+                Some(Self {
+                    modulus,
+                    normalized,
+                    shift,
+                    mu: <$t>::from_le_limbs(mu),
+                })
+            }
+
+            /// Get the modulus of the context.
+            #[inline(always)]
+            pub const fn modulus(&self) -> $t {
+                self.modulus
+            }
+
+            /// Reduce the double-width value `hi * 2^BITS + lo` by the
+            /// normalized modulus, where the value is less than `2^(2 * BITS)`.
+            ///
+            /// The quotient estimate is never larger than the actual quotient
+            /// and is at most 4 smaller, so this requires at most 4 final
+            /// subtractions.
+            #[inline]
+            const fn reduce_normalized(&self, lo: $t, hi: $t) -> $t {
+                let bits = <$t>::BITS;
+                let one = <$t>::from_u8(1);
+                let m = self.normalized;
+
+                // `q1 = x >> (BITS - 1)`, which has up to `BITS + 1` bits.
+                let q1_lo = hi.wrapping_shl(1).bitor_const(lo.wrapping_shr(bits - 1));
+                let q1_hi = hi.wrapping_shr(bits - 1).eq_const(one);
+
+                // `q3 = (q1 * mu) >> (BITS + 1)`, where `mu = 2^BITS + mu_lo`,
+                // truncating the low half of `q1_lo * mu_lo`. We track the
+                // additions as a sum in units of `2^BITS` and its carries.
+                let (mut sum, c0) = q1_lo.high_mul(self.mu).overflowing_add(q1_lo);
+                let mut carries = c0 as u32;
+                if q1_hi {
+                    let (value, c1) = sum.overflowing_add(self.mu);
+                    sum = value;
+                    carries += c1 as u32 + 1;
+                }
+                let q3_lo = sum.wrapping_shr(1).bitor_const(<$t>::from_u32(carries & 1).wrapping_shl(bits - 1));
+                let q3_hi = carries >> 1 != 0;
+
+                // `r = x - q3 * m`, which is exact modulo `2^(2 * BITS)`.
+                let (p_lo, mut p_hi) = q3_lo.widening_mul(m);
+                if q3_hi {
+                    p_hi = p_hi.wrapping_add(m);
+                }
+                let (mut r_lo, borrow) = lo.overflowing_sub(p_lo);
+                let mut r_hi = hi.wrapping_sub(p_hi).wrapping_sub(<$t>::from_u8(borrow as u8));
+                while !r_hi.eq_const(<$t>::from_u8(0)) || r_lo.ge_const(m) {
+                    let (value, borrow) = r_lo.overflowing_sub(m);
+                    r_lo = value;
+                    r_hi = r_hi.wrapping_sub(<$t>::from_u8(borrow as u8));
+                }
+
+                r_lo
+            }
+
+            /// Reduce the double-width value `hi * 2^BITS + lo` by the modulus,
+            /// such as the result of a [`widening_mul`].
+            ///
+            /// This requires at most 2 reductions by the normalized modulus,
+            /// and only 1 if the value is less than `m * 2^BITS`, such as
+            /// for the product of 2 values less than the modulus.
+            ///
+            #[doc = concat!("[`widening_mul`]: ", stringify!($t), "::widening_mul")]
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn reduce_wide(&self, lo: $t, hi: $t) -> $t {
+                let bits = <$t>::BITS;
+                let s = self.shift;
+
+                // `x << s`, split into the low, middle and top parts.
+                let (low, mid, top) = if s == 0 {
+                    (lo, hi, <$t>::from_u8(0))
+                } else {
+                    let low = lo.wrapping_shl(s);
+                    let mid = hi.wrapping_shl(s).bitor_const(lo.wrapping_shr(bits - s));
+                    (low, mid, hi.wrapping_shr(bits - s))
+                };
+
+                let r = if top.eq_const(<$t>::from_u8(0)) && mid.lt_const(self.normalized) {
+                    mid
+                } else {
+                    self.reduce_normalized(mid, top)
+                };
+                self.reduce_normalized(low, r).wrapping_shr(s)
+            }
+
+            /// Modular multiplication, or `x * y mod m`.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn mul(&self, x: $t, y: $t) -> $t {
+                let (lo, hi) = x.widening_mul(y);
+                self.reduce_wide(lo, hi)
+            }
+        }
+    };
+
+    (type => $t:ty, wide_type => $w:ty $(,)?) => {
+        impl $crate::barrett::Barrett<$t> {
+            #[doc = concat!("Reduce a [`", stringify!($w), "`] value by the modulus.")]
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn reduce(&self, x: $w) -> $t {
+                let limbs = x.to_le_limbs();
+                let mut lo = [0; <$t>::LIMBS];
+                let mut hi = [0; <$t>::LIMBS];
+                let mut i = 0;
+                while i < <$t>::LIMBS {
+                    lo[i] = limbs[i];
+                    hi[i] = limbs[i + <$t>::LIMBS];
+                    i += 1;
+                }
+                self.reduce_wide(<$t>::from_le_limbs(lo), <$t>::from_le_limbs(hi))
+            }
+        }
+    };
+}
+
+pub(crate) use define;
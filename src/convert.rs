@@ -0,0 +1,343 @@
+//! Conversions between our integer types of different widths.
+//!
+//! These work on the little-endian limbs of each type, so a single
+//! implementation handles all pairs of widths and signedness. Lossless
+//! conversions implement [`From`], and all others [`TryFrom`]. Types
+//! that are exactly twice as wide can also be composed from, and split
+//! into, their halves.
+
+// NOTE: The conversions are only defined if there are multiple widths.
+#![cfg_attr(
+    not(any(feature = "i384", feature = "i512", feature = "i1024")),
+    allow(dead_code, unused_macros, unused_imports)
+)]
+
+use crate::{ILimb, ULimb};
+
+/// Get if the little-endian limbs are for a negative value.
+#[inline(always)]
+pub(crate) const fn is_negative<const M: usize>(x: &[ULimb; M], is_signed: bool) -> bool {
+    is_signed && M != 0 && (x[M - 1] as ILimb) < 0
+}
+
+/// Resize the little-endian limbs, truncating any high limbs, or sign- or
+/// zero-extending the value, like an `as` cast between primitives.
+#[inline(always)]
+pub(crate) const fn wrapping<const M: usize, const N: usize>(
+    x: &[ULimb; M],
+    is_signed: bool,
+) -> [ULimb; N] {
+    let ext = if is_negative(x, is_signed) {
+        ULimb::MAX
+    } else {
+        0
+    };
+    let mut r = [ext; N];
+    let mut i = 0;
+    while i < M && i < N {
+        r[i] = x[i];
+        i += 1;
+    }
+    r
+}
+
+/// Get if the value with the little-endian limbs can be represented with
+/// `N` limbs.
+#[inline(always)]
+const fn fits<const M: usize, const N: usize>(
+    x: &[ULimb; M],
+    from_signed: bool,
+    to_signed: bool,
+) -> bool {
+    let is_negative = is_negative(x, from_signed);
+    if is_negative && !to_signed {
+        return false;
+    }
+
+    // All the truncated limbs must be the sign extension of the result.
+    let ext = if is_negative {
+        ULimb::MAX
+    } else {
+        0
+    };
+    let mut i = N;
+    while i < M {
+        if x[i] != ext {
+            return false;
+        }
+        i += 1;
+    }
+
+    // NOTE: If we're extending the value, the sign is always preserved.
+    // Otherwise, the result must have the same sign as the value.
+    !to_signed || N > M || N == 0 || is_negative == ((x[N - 1] as ILimb) < 0)
+}
+
+/// Resize the little-endian limbs, returning `None` if the value cannot be
+/// represented.
+#[inline(always)]
+pub(crate) const fn checked<const M: usize, const N: usize>(
+    x: &[ULimb; M],
+    from_signed: bool,
+    to_signed: bool,
+) -> Option<[ULimb; N]> {
+    if fits::<M, N>(x, from_signed, to_signed) {
+        Some(wrapping(x, from_signed))
+    } else {
+        None
+    }
+}
+
+/// Resize the little-endian limbs, saturating at the bounds of the result.
+#[inline(always)]
+pub(crate) const fn saturating<const M: usize, const N: usize>(
+    x: &[ULimb; M],
+    from_signed: bool,
+    to_signed: bool,
+) -> [ULimb; N] {
+    if fits::<M, N>(x, from_signed, to_signed) {
+        return wrapping(x, from_signed);
+    }
+
+    let is_negative = is_negative(x, from_signed);
+    let mut r = match is_negative {
+        true => [0; N],
+        false => [ULimb::MAX; N],
+    };
+    if to_signed && N != 0 {
+        r[N - 1] = match is_negative {
+            true => ILimb::MIN as ULimb,
+            false => ILimb::MAX as ULimb,
+        };
+    }
+    r
+}
+
+/// Concatenate the little-endian limbs of the low and high halves.
+#[cfg(feature = "i512")]
+#[inline(always)]
+pub(crate) const fn concat<const M: usize, const N: usize>(
+    lo: &[ULimb; M],
+    hi: &[ULimb; M],
+) -> [ULimb; N] {
+    assert!(N == 2 * M, "the result must be twice the width of the halves");
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < M {
+        r[i] = lo[i];
+        r[i + M] = hi[i];
+        i += 1;
+    }
+    r
+}
+
+/// Split the little-endian limbs into the low and high halves.
+#[cfg(feature = "i512")]
+#[inline(always)]
+pub(crate) const fn split<const M: usize, const N: usize>(
+    x: &[ULimb; N],
+) -> ([ULimb; M], [ULimb; M]) {
+    assert!(N == 2 * M, "the value must be twice the width of the halves");
+    let mut lo = [0; M];
+    let mut hi = [0; M];
+    let mut i = 0;
+    while i < M {
+        lo[i] = x[i];
+        hi[i] = x[i + M];
+        i += 1;
+    }
+    (lo, hi)
+}
+
+#[rustfmt::skip]
+macro_rules! define {
+    (@From $t:ty, $from:ty, $wrapping:ident, $checked:ident) => {
+        impl From<$from> for $t {
+            #[inline(always)]
+            fn from(value: $from) -> Self {
+                Self::$wrapping(value)
+            }
+        }
+    };
+
+    (@TryFrom $t:ty, $from:ty, $wrapping:ident, $checked:ident) => {
+        impl TryFrom<$from> for $t {
+            type Error = $crate::TryFromIntError;
+
+            #[inline(always)]
+            fn try_from(value: $from) -> Result<Self, $crate::TryFromIntError> {
+                match Self::$checked(value) {
+                    Some(v) => Ok(v),
+                    None => Err($crate::TryFromIntError {}),
+                }
+            }
+        }
+    };
+
+    (
+        type => $t:ty,
+        $(
+            from => $from:ty,
+            impl => $trait:ident,
+            wrapping => $wrapping:ident,
+            saturating => $saturating:ident,
+            checked => $checked:ident $(,)?
+        );* $(;)?
+    ) => {
+        impl $t {
+            $(
+                #[doc = concat!("Create a new value from a [`", stringify!($from), "`], wrapping on overflow.")]
+                ///
+                /// This truncates any high bits, and sign-extends signed values,
+                /// like an `as` cast between primitive integers.
+                #[inline(always)]
+                #[must_use]
+                pub const fn $wrapping(value: $from) -> Self {
+                    let limbs = value.to_le_limbs();
+                    Self::from_le_limbs($crate::convert::wrapping(&limbs, <$from>::IS_SIGNED))
+                }
+
+                #[doc = concat!("Create a new value from a [`", stringify!($from), "`], saturating at the numeric bounds")]
+                /// instead of overflowing.
+                #[inline(always)]
+                #[must_use]
+                pub const fn $saturating(value: $from) -> Self {
+                    let limbs = value.to_le_limbs();
+                    let limbs = $crate::convert::saturating(&limbs, <$from>::IS_SIGNED, Self::IS_SIGNED);
+                    Self::from_le_limbs(limbs)
+                }
+
+                #[doc = concat!("Create a new value from a [`", stringify!($from), "`], returning `None` if the value")]
+                /// cannot be represented.
+                #[inline(always)]
+                #[must_use]
+                pub const fn $checked(value: $from) -> Option<Self> {
+                    let limbs = value.to_le_limbs();
+                    match $crate::convert::checked(&limbs, <$from>::IS_SIGNED, Self::IS_SIGNED) {
+                        Some(limbs) => Some(Self::from_le_limbs(limbs)),
+                        None => None,
+                    }
+                }
+            )*
+        }
+
+        $(
+            $crate::convert::define!(@$trait $t, $from, $wrapping, $checked);
+        )*
+    };
+}
+
+pub(crate) use define;
+
+#[cfg(feature = "i512")]
+#[rustfmt::skip]
+macro_rules! halves {
+    (
+        narrow => ($nu:ty, $ns:ty),
+        wide => ($wu:ty, $ws:ty) $(,)?
+    ) => {
+        impl $wu {
+            #[doc = concat!("Create a new value from the low and high halves, as [`", stringify!($nu), "`].")]
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_halves(lo: $nu, hi: $nu) -> Self {
+                Self::from_le_limbs($crate::convert::concat(&lo.to_le_limbs(), &hi.to_le_limbs()))
+            }
+
+            #[doc = concat!("Split the value into the low and high halves, as [`", stringify!($nu), "`].")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn split(self) -> ($nu, $nu) {
+                let (lo, hi) = $crate::convert::split(&self.to_le_limbs());
+                (<$nu>::from_le_limbs(lo), <$nu>::from_le_limbs(hi))
+            }
+        }
+
+        impl $ws {
+            #[doc = concat!("Create a new value from the unsigned low half, as [`", stringify!($nu), "`],")]
+            #[doc = concat!("and the signed high half, as [`", stringify!($ns), "`].")]
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_halves(lo: $nu, hi: $ns) -> Self {
+                Self::from_le_limbs($crate::convert::concat(&lo.to_le_limbs(), &hi.to_le_limbs()))
+            }
+
+            #[doc = concat!("Split the value into the unsigned low half, as [`", stringify!($nu), "`],")]
+            #[doc = concat!("and the signed high half, as [`", stringify!($ns), "`].")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn split(self) -> ($nu, $ns) {
+                let (lo, hi) = $crate::convert::split(&self.to_le_limbs());
+                (<$nu>::from_le_limbs(lo), <$ns>::from_le_limbs(hi))
+            }
+        }
+
+        impl $nu {
+            #[doc = concat!("Calculates the complete product `self * rhs` as a [`", stringify!($wu), "`].")]
+            ///
+            /// This is the same as [`widening_mul`][Self::widening_mul], but
+            /// combines the low and high halves into a single value.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_mul_into(self, rhs: Self) -> $wu {
+                let (lo, hi) = self.widening_mul(rhs);
+                <$wu>::from_halves(lo, hi)
+            }
+
+            #[doc = concat!("Calculates the complete sum `self + rhs` as a [`", stringify!($wu), "`].")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_add(self, rhs: Self) -> $wu {
+                let (lo, carry) = self.overflowing_add(rhs);
+                <$wu>::from_halves(lo, Self::from_u8(carry as u8))
+            }
+
+            #[doc = concat!("Calculates the complete difference `self - rhs` as a [`", stringify!($ws), "`].")]
+            ///
+            /// The difference may be negative, so this returns the signed type.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_sub(self, rhs: Self) -> $ws {
+                let (lo, borrow) = self.overflowing_sub(rhs);
+                <$ws>::from_halves(lo, <$ns>::from_i8(-(borrow as i8)))
+            }
+        }
+
+        impl $ns {
+            #[doc = concat!("Calculates the complete product `self * rhs` as a [`", stringify!($ws), "`].")]
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_mul_into(self, rhs: Self) -> $ws {
+                let (lo, hi) = self.unsigned_abs().widening_mul(rhs.unsigned_abs());
+                let product = <$wu>::from_halves(lo, hi);
+                // NOTE: The magnitude is at most `2^(2 * BITS - 2)`, so this can't overflow.
+                if self.is_negative() ^ rhs.is_negative() {
+                    product.wrapping_neg().as_signed()
+                } else {
+                    product.as_signed()
+                }
+            }
+
+            #[doc = concat!("Calculates the complete sum `self + rhs` as a [`", stringify!($ws), "`].")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_add(self, rhs: Self) -> $ws {
+                let x = <$ws>::from_le_limbs($crate::convert::wrapping(&self.to_le_limbs(), true));
+                let y = <$ws>::from_le_limbs($crate::convert::wrapping(&rhs.to_le_limbs(), true));
+                x.wrapping_add(y)
+            }
+
+            #[doc = concat!("Calculates the complete difference `self - rhs` as a [`", stringify!($ws), "`].")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn widening_sub(self, rhs: Self) -> $ws {
+                let x = <$ws>::from_le_limbs($crate::convert::wrapping(&self.to_le_limbs(), true));
+                let y = <$ws>::from_le_limbs($crate::convert::wrapping(&rhs.to_le_limbs(), true));
+                x.wrapping_sub(y)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i512")]
+pub(crate) use halves;
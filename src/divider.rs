@@ -0,0 +1,174 @@
+//! Division by an invariant divisor using multiplication.
+//!
+//! This precomputes a magic multiplier and shift for the divisor, so
+//! each division only requires a high multiplication, an addition and
+//! shifts. This is the unsigned, branching algorithm from [`libdivide`],
+//! which is prototyped in `etc/libdivide.py`.
+//!
+//! [`libdivide`]: https://libdivide.com/documentation.html
+
+/// A precomputed divisor for repeated division by the same value.
+///
+/// The numerators are of type `T`, and the divisor and remainders are
+/// of type `D`, which may be `T`, [`ULimb`] or [`UWide`]. For a divisor
+/// `d` that is not a power of two, with `k = floor(log2(d))`, the magic
+/// multiplier is `m = floor(2^(BITS + k) / d) + 1`, and the quotient is
+/// `mulhi(m, n) >> k`. If the error in `m` is too large, this instead uses
+/// a `BITS + 1`-bit multiplier, where the implicit high bit is handled by
+/// an additional add-and-shift. Powers of two only require a shift.
+///
+/// The constructors are `const`, so the divisor can be a constant.
+///
+/// ```rust
+/// use i256::{Divider, U256};
+///
+/// const DIV: Divider<U256> = Divider::<U256>::new(U256::from_u64(10u64.pow(18)));
+/// let (quo, rem) = DIV.div_rem(U256::MAX);
+/// assert_eq!((quo, rem), U256::MAX.wrapping_div_rem(DIV.divisor()));
+/// assert_eq!(DIV.div(U256::from_u64(3_000_000_000_000_000_001)), U256::from_u8(3));
+/// assert_eq!(DIV.rem(U256::from_u64(3_000_000_000_000_000_001)), U256::from_u8(1));
+/// ```
+///
+/// [`ULimb`]: crate::ULimb
+/// [`UWide`]: crate::UWide
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Divider<T, D = T> {
+    pub(crate) divisor: D,
+    pub(crate) magic: T,
+    pub(crate) shift: u32,
+    pub(crate) add: bool,
+}
+
+#[rustfmt::skip]
+macro_rules! define {
+    (type => $t:ty $(,)?) => {
+        $crate::divider::define!(@impl type => $t, divisor => $t, from => from_unsigned, to => as_unsigned);
+        $crate::divider::define!(@impl type => $t, divisor => $crate::ULimb, from => from_ulimb, to => as_ulimb);
+        $crate::divider::define!(@impl type => $t, divisor => $crate::UWide, from => from_uwide, to => as_uwide);
+    };
+
+    (@impl type => $t:ty, divisor => $d:ty, from => $from:ident, to => $to:ident $(,)?) => {
+        impl $crate::divider::Divider<$t, $d> {
+            /// Create a new divider for the `divisor`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `divisor` is zero.
+            #[inline]
+            #[must_use]
+            pub const fn new(divisor: $d) -> Self {
+                match Self::checked_new(divisor) {
+                    Some(divider) => divider,
+                    None => panic!("Divider divisor must be non-zero"),
+                }
+            }
+
+            /// Create a new divider for the `divisor`, returning `None` if
+            /// the divisor is zero.
+            #[inline]
+            #[must_use]
+            pub const fn checked_new(divisor: $d) -> Option<Self> {
+                let d = <$t>::$from(divisor);
+                if d.eq_const(<$t>::from_u8(0)) {
+                    return None;
+                }
+
+                let k = <$t>::BITS - 1 - d.leading_zeros();
+                let zero = <$t>::from_u8(0);
+                if d.bitand_const(d.wrapping_sub(<$t>::from_u8(1))).eq_const(zero) {
+                    return Some(Self {
+                        divisor,
+                        magic: zero,
+                        shift: k,
+                        add: false,
+                    });
+                }
+
+                // NOTE: Since `d` is not a power of two, `2^k < d`, so the
+                // quotient of `2^(BITS + k) / d` fits in `BITS` bits. This
+                // uses binary long division, since the dividend is `2 * BITS` wide.
+                let mut quo = zero;
+                let mut rem = <$t>::from_u8(1).wrapping_shl(k);
+                let mut i = 0;
+                while i < <$t>::BITS {
+                    let carry = rem.leading_zeros() == 0;
+                    rem = rem.wrapping_shl(1);
+                    quo = quo.wrapping_shl(1);
+                    if carry || rem.ge_const(d) {
+                        rem = rem.wrapping_sub(d);
+                        quo = quo.bitor_const(<$t>::from_u8(1));
+                    }
+                    i += 1;
+                }
+
+                // NOTE: If the error `e = d - rem < 2^k`, then `m` is exact
+                // for all numerators. Otherwise, we need `floor(2^(BITS + k + 1) / d)`,
+                // which has `BITS + 1` bits, so we store the low bits.
+                let e = d.wrapping_sub(rem);
+                let add = !e.lt_const(<$t>::from_u8(1).wrapping_shl(k));
+                if add {
+                    quo = quo.wrapping_shl(1);
+                    let (twice_rem, overflowed) = rem.overflowing_add(rem);
+                    if overflowed || twice_rem.ge_const(d) {
+                        quo = quo.wrapping_add(<$t>::from_u8(1));
+                    }
+                }
+
+                Some(Self {
+                    divisor,
+                    magic: quo.wrapping_add(<$t>::from_u8(1)),
+                    shift: k,
+                    add,
+                })
+            }
+
+            /// Get the divisor.
+            #[inline(always)]
+            pub const fn divisor(&self) -> $d {
+                self.divisor
+            }
+
+            /// Calculate the quotient `n / d`.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn div(&self, n: $t) -> $t {
+                if self.magic.eq_const(<$t>::from_u8(0)) {
+                    return n.wrapping_shr(self.shift);
+                }
+
+                let q = self.magic.high_mul(n);
+                if self.add {
+                    // NOTE: This is `(n * (2^BITS + m)) >> (BITS + 1 + k)`,
+                    // calculated without overflowing, since `q <= n`.
+                    let t = n.wrapping_sub(q).wrapping_shr(1).wrapping_add(q);
+                    t.wrapping_shr(self.shift)
+                } else {
+                    q.wrapping_shr(self.shift)
+                }
+            }
+
+            /// Calculate the remainder `n % d`.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn rem(&self, n: $t) -> $d {
+                self.div_rem(n).1
+            }
+
+            /// Calculate the quotient and remainder `(n / d, n % d)`.
+            ///
+            /// The remainder only requires an additional wrapping multiply
+            /// and subtraction, so this is no slower than [`div`].
+            ///
+            /// [`div`]: Self::div
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn div_rem(&self, n: $t) -> ($t, $d) {
+                let quo = self.div(n);
+                let rem = n.wrapping_sub(quo.wrapping_mul(<$t>::$from(self.divisor)));
+                (quo, rem.$to())
+            }
+        }
+    };
+}
+
+pub(crate) use define;
@@ -3,6 +3,7 @@
 use core::{fmt, num};
 
 /// The error type returned when a checked integral type conversion fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TryFromIntError;
 
 impl fmt::Display for TryFromIntError {
@@ -0,0 +1,89 @@
+//! A fixed-capacity prime factorization.
+//!
+//! This stores the distinct prime factors of an integer and their
+//! exponents inline, without any allocation. The capacity is chosen
+//! for each type so the factorization of any value always fits.
+
+use core::fmt;
+use core::ops::Deref;
+
+/// The prime factorization of an integer, as `(prime, exponent)` pairs.
+///
+/// The primes are sorted in ascending order, and each is unique. This
+/// dereferences to a slice of the pairs, and the capacity `N` is always
+/// large enough for the factorization of any value of the type.
+///
+/// ```rust
+/// use i256::U256;
+///
+/// let factors = U256::from_u16(360).factor();
+/// let expected = [(U256::from_u8(2), 3), (U256::from_u8(3), 2), (U256::from_u8(5), 1)];
+/// assert_eq!(&*factors, &expected);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Factors<T, const N: usize> {
+    pub(crate) factors: [(T, u32); N],
+    pub(crate) len: usize,
+}
+
+impl<T: Copy + Ord, const N: usize> Factors<T, N> {
+    /// Create an empty factorization, filling any unused slots with `zero`.
+    #[inline(always)]
+    pub(crate) fn new(zero: T) -> Self {
+        Self {
+            factors: [(zero, 0); N],
+            len: 0,
+        }
+    }
+
+    /// Add `prime^exp` to the factorization, keeping the primes sorted.
+    #[inline]
+    pub(crate) fn insert(&mut self, prime: T, exp: u32) {
+        let mut index = self.len;
+        for (i, factor) in self.factors[..self.len].iter_mut().enumerate() {
+            if factor.0 == prime {
+                factor.1 += exp;
+                return;
+            } else if factor.0 > prime {
+                index = i;
+                break;
+            }
+        }
+        self.factors.copy_within(index..self.len, index + 1);
+        self.factors[index] = (prime, exp);
+        self.len += 1;
+    }
+}
+
+impl<T, const N: usize> Factors<T, N> {
+    /// Get the `(prime, exponent)` pairs as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[(T, u32)] {
+        &self.factors[..self.len]
+    }
+}
+
+impl<T, const N: usize> Deref for Factors<T, N> {
+    type Target = [(T, u32)];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Factors<T, N> {
+    type Item = &'a (T, u32);
+    type IntoIter = core::slice::Iter<'a, (T, u32)>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Factors<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,425 @@
+//! Prime field elements with a compile-time modulus.
+//!
+//! Elements are stored in the Montgomery form, with all the Montgomery
+//! constants for the modulus calculated at compile time, so field
+//! multiplication never requires division.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::error::{IntErrorKind, ParseIntError};
+use crate::{Montgomery, U256};
+
+/// The modulus of a prime field, used to define a distinct field type.
+///
+/// The modulus must be an odd prime: for any odd modulus, all the
+/// arithmetic operations are still correct modulo `MODULUS`, however,
+/// [`Fp::inv`] and [`Fp::sqrt`] may fail.
+///
+/// ```rust
+/// use i256::{Fp, Modulus, U256};
+///
+/// #[derive(Copy, Clone, Debug)]
+/// struct Ed25519;
+///
+/// impl Modulus for Ed25519 {
+///     // 2^255 - 19
+///     const MODULUS: U256 = U256::MAX.wrapping_shr(1).wrapping_sub(U256::from_u8(18));
+/// }
+///
+/// let x = Fp::<Ed25519>::from_u64(3);
+/// let y = -x;
+/// assert_eq!(x + y, Fp::ZERO);
+/// assert_eq!((x * x).sqrt().map(|r| r == x || r == y), Some(true));
+/// ```
+pub trait Modulus {
+    /// The odd, prime modulus of the field.
+    const MODULUS: U256;
+}
+
+/// Double `x` modulo `n`, where `x < n`.
+const fn double_mod(x: U256, n: U256) -> U256 {
+    let (sum, overflowed) = x.overflowing_add(x);
+    if overflowed || sum.ge_const(n) {
+        sum.wrapping_sub(n)
+    } else {
+        sum
+    }
+}
+
+/// Calculate `2^exp mod n`, by repeated modular doubling.
+const fn pow2_mod(exp: u32, n: U256) -> U256 {
+    let mut value = match n.eq_const(U256::from_u8(1)) {
+        true => U256::from_u8(0),
+        false => U256::from_u8(1),
+    };
+    let mut i = 0;
+    while i < exp {
+        value = double_mod(value, n);
+        i += 1;
+    }
+    value
+}
+
+/// An element of the prime field with the modulus `M`.
+///
+/// This supports the field operations with the standard operators,
+/// where division panics if the divisor is zero, along with [`inv`],
+/// [`pow`] and [`sqrt`]. The [`Display`] and [`FromStr`] implementations
+/// use the canonical, decimal representation of the element, which must
+/// be less than the modulus when parsed.
+///
+/// [`inv`]: Fp::inv
+/// [`pow`]: Fp::pow
+/// [`sqrt`]: Fp::sqrt
+/// [`Display`]: fmt::Display
+pub struct Fp<M: Modulus> {
+    // NOTE: This is always in the Montgomery form, and less than `M::MODULUS`.
+    value: U256,
+    marker: PhantomData<M>,
+}
+
+impl<M: Modulus> Fp<M> {
+    /// The Montgomery context for the modulus.
+    const CTX: Montgomery<U256> = {
+        let modulus = M::MODULUS;
+        assert!(modulus.is_odd(), "field modulus must be odd");
+        assert!(modulus.gt_const(U256::from_u8(1)), "field modulus must be greater than 1");
+        Montgomery {
+            modulus,
+            r: pow2_mod(U256::BITS, modulus),
+            r2: pow2_mod(2 * U256::BITS, modulus),
+            neg_inv: crate::math::mont::neg_inv(modulus.least_significant_limb()),
+        }
+    };
+
+    /// The additive identity.
+    pub const ZERO: Self = Self {
+        value: U256::from_u8(0),
+        marker: PhantomData,
+    };
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self {
+        value: Self::CTX.r,
+        marker: PhantomData,
+    };
+
+    /// The modulus of the field.
+    pub const MODULUS: U256 = M::MODULUS;
+
+    #[inline(always)]
+    fn from_mont(value: U256) -> Self {
+        Self {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create the field element from an integer, reducing it by the
+    /// modulus.
+    #[inline]
+    #[must_use]
+    pub fn new(value: U256) -> Self {
+        // NOTE: Montgomery multiplication only requires one of the operands
+        // to be less than the modulus for a fully-reduced result, since the
+        // product is always less than `R * N`.
+        Self::from_mont(Self::CTX.mul(value, Self::CTX.r2))
+    }
+
+    /// Create the field element from a `u64`, reducing it by the modulus.
+    #[inline]
+    #[must_use]
+    pub fn from_u64(value: u64) -> Self {
+        Self::new(U256::from_u64(value))
+    }
+
+    /// Get the canonical integer value of the element, which is always
+    /// less than the modulus.
+    #[inline]
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn to_u256(self) -> U256 {
+        Self::CTX.from_mont(self.value)
+    }
+
+    /// Get if the element is the additive identity.
+    #[inline(always)]
+    pub fn is_zero(self) -> bool {
+        self.value.eq_const(U256::from_u8(0))
+    }
+
+    /// Field squaring.
+    #[inline]
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn square(self) -> Self {
+        Self::from_mont(Self::CTX.square(self.value))
+    }
+
+    /// Raises the element to the power of `exp`.
+    ///
+    /// `0^0` is defined as `1`.
+    #[inline]
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn pow(self, exp: U256) -> Self {
+        Self::from_mont(Self::CTX.pow(self.value, exp))
+    }
+
+    /// Multiplicative inverse of the element.
+    ///
+    /// Returns `None` if the element is zero, or for a composite modulus,
+    /// if the element is not coprime to the modulus.
+    #[inline]
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn inv(self) -> Option<Self> {
+        self.to_u256().inv_mod(M::MODULUS).map(Self::new)
+    }
+
+    /// Checked field division. Computes `self / rhs`, returning `None`
+    /// if `rhs` has no inverse.
+    #[inline]
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        rhs.inv().map(|inv| self * inv)
+    }
+
+    /// Square root of the element.
+    ///
+    /// Returns one of the two square roots, or `None` if the element is
+    /// not a quadratic residue. If the modulus is `3 mod 4`, this uses a
+    /// single exponentiation, otherwise, this uses the Tonelli-Shanks
+    /// algorithm.
+    #[must_use = crate::shared::docs::must_use_copy_doc!()]
+    pub fn sqrt(self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self);
+        }
+
+        let one = U256::from_u8(1);
+        let modulus = M::MODULUS;
+        let root = if modulus.least_significant_limb() & 3 == 3 {
+            // `x^((p + 1) / 4)`, where `p + 1` may overflow.
+            self.pow(modulus.wrapping_shr(2).wrapping_add(one))
+        } else {
+            // NOTE: `p - 1 = q * 2^s`, with `q` odd.
+            let p_minus_1 = modulus.wrapping_sub(one);
+            let mut m = p_minus_1.trailing_zeros();
+            let q = p_minus_1.wrapping_shr(m);
+            let z = Self::non_residue()?;
+
+            let mut c = z.pow(q);
+            let mut t = self.pow(q);
+            let mut r = self.pow(q.wrapping_shr(1).wrapping_add(one));
+            while t != Self::ONE {
+                // find the least `i` such that `t^(2^i) == 1`
+                let mut i = 0;
+                let mut t2i = t;
+                while t2i != Self::ONE {
+                    t2i = t2i.square();
+                    i += 1;
+                    if i == m {
+                        return None;
+                    }
+                }
+
+                let mut b = c;
+                let mut j = 0;
+                while j < m - i - 1 {
+                    b = b.square();
+                    j += 1;
+                }
+                m = i;
+                c = b.square();
+                t *= c;
+                r *= b;
+            }
+            r
+        };
+
+        match root.square() == self {
+            true => Some(root),
+            false => None,
+        }
+    }
+
+    /// Find the smallest quadratic non-residue, using Euler's criterion.
+    ///
+    /// Returns `None` if the modulus is shown to not be prime.
+    fn non_residue() -> Option<Self> {
+        let exp = M::MODULUS.wrapping_shr(1);
+        let minus_one = -Self::ONE;
+        let mut z = Self::ONE + Self::ONE;
+        loop {
+            let euler = z.pow(exp);
+            if euler == minus_one {
+                return Some(z);
+            } else if euler != Self::ONE {
+                return None;
+            }
+            z += Self::ONE;
+        }
+    }
+}
+
+impl<M: Modulus> Clone for Fp<M> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: Modulus> Copy for Fp<M> {
+}
+
+impl<M: Modulus> Default for Fp<M> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<M: Modulus> PartialEq for Fp<M> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq_const(other.value)
+    }
+}
+
+impl<M: Modulus> Eq for Fp<M> {
+}
+
+impl<M: Modulus> Hash for Fp<M> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<M: Modulus> fmt::Debug for Fp<M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Fp").field(&self.to_u256()).finish()
+    }
+}
+
+impl<M: Modulus> fmt::Display for Fp<M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_u256(), f)
+    }
+}
+
+impl<M: Modulus> FromStr for Fp<M> {
+    type Err = ParseIntError;
+
+    /// Parses a decimal string, which must be less than the modulus.
+    #[inline]
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        let value = U256::from_str_radix(src, 10)?;
+        match value.lt_const(M::MODULUS) {
+            true => Ok(Self::new(value)),
+            false => Err(ParseIntError::new(IntErrorKind::PosOverflow)),
+        }
+    }
+}
+
+impl<M: Modulus> From<U256> for Fp<M> {
+    #[inline(always)]
+    fn from(value: U256) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<M: Modulus> From<u64> for Fp<M> {
+    #[inline(always)]
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+impl<M: Modulus> Add for Fp<M> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        let modulus = M::MODULUS;
+        let (sum, overflowed) = self.value.overflowing_add(rhs.value);
+        if overflowed || sum.ge_const(modulus) {
+            Self::from_mont(sum.wrapping_sub(modulus))
+        } else {
+            Self::from_mont(sum)
+        }
+    }
+}
+
+impl<M: Modulus> Sub for Fp<M> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        match self.value.overflowing_sub(rhs.value) {
+            (diff, true) => Self::from_mont(diff.wrapping_add(M::MODULUS)),
+            (diff, false) => Self::from_mont(diff),
+        }
+    }
+}
+
+impl<M: Modulus> Mul for Fp<M> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_mont(Self::CTX.mul(self.value, rhs.value))
+    }
+}
+
+impl<M: Modulus> Div for Fp<M> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        match self.checked_div(rhs) {
+            Some(value) => value,
+            None => panic!("attempt to divide by a non-invertible element"),
+        }
+    }
+}
+
+impl<M: Modulus> Neg for Fp<M> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<M: Modulus> AddAssign for Fp<M> {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<M: Modulus> SubAssign for Fp<M> {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<M: Modulus> MulAssign for Fp<M> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<M: Modulus> DivAssign for Fp<M> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
@@ -90,14 +90,88 @@ macro_rules! define {
             }
         }
 
-        // FIXME: Stabilize when our MSRV goes to `1.84.0+`.
-        // /// Returns the square root of the number, rounded down.
-        // ///
-        // /// Returns `None` if `self` is negative.
-        // #[inline]
-        // pub const fn checked_isqrt(self) -> Option<Self> {
-        //     todo!();
-        // }
+        /// Returns the square root of the number, rounded down.
+        ///
+        /// Returns `None` if `self` is negative.
+        ///
+        #[doc = $crate::shared::docs::primitive_doc!($see_t, checked_isqrt)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_isqrt(self) -> Option<Self> {
+            match self.is_negative() {
+                true => None,
+                // NOTE: The root of a non-negative value is always less than `MAX`.
+                false => Some(self.as_unsigned().isqrt().as_signed()),
+            }
+        }
+
+        /// Returns the `n`th root of the number, rounded toward zero.
+        ///
+        /// Returns `None` if `n` is zero, or if `self` is negative and `n`
+        /// is even.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_iroot(self, n: u32) -> Option<Self> {
+            if self.is_negative() && n % 2 == 0 {
+                return None;
+            }
+            // NOTE: The only root that can overflow is `MIN.iroot(1)`,
+            // which wraps back to `MIN`.
+            let root = self.unsigned_abs().checked_iroot(n)?.as_signed();
+            match self.is_negative() {
+                true => Some(root.wrapping_neg()),
+                false => Some(root),
+            }
+        }
+
+        /// Computes the least common multiple of `self` and `rhs`, returning
+        /// `None` if overflow occurred.
+        ///
+        /// The LCM is always non-negative, so this returns the unsigned type.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_lcm(self, rhs: Self) -> Option<$u_t> {
+            self.unsigned_abs().checked_lcm(rhs.unsigned_abs())
+        }
+
+        /// Calculates `self * b / c`, rounded toward zero, returning `None`
+        /// if `c` is zero or the quotient overflows.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_mul_div(self, b: Self, c: Self) -> Option<Self> {
+            match self.checked_mul_div_rem(b, c) {
+                Some((quo, _)) => Some(quo),
+                None => None,
+            }
+        }
+
+        /// Calculates the quotient and remainder of `self * b / c`, returning
+        /// `None` if `c` is zero or the quotient overflows.
+        ///
+        /// The quotient is rounded toward zero, so the remainder has the
+        /// same sign as `self * b`, like the `/` and `%` operators.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_mul_div_rem(self, b: Self, c: Self) -> Option<(Self, Self)> {
+            let product_is_negative = self.is_negative() != b.is_negative();
+            let quo_is_negative = product_is_negative != c.is_negative();
+            let (quo, rem) = self.unsigned_abs().checked_mul_div_rem(b.unsigned_abs(), c.unsigned_abs())?;
+
+            // NOTE: The magnitude of a negative quotient can be 1 larger than `MAX`.
+            let max = Self::MAX.as_unsigned().wrapping_add(<$u_t>::from_u8(quo_is_negative as u8));
+            if quo.gt_const(max) {
+                return None;
+            }
+            let quo = Self::from_unsigned(quo);
+            let quo = if quo_is_negative { quo.wrapping_neg() } else { quo };
+            // NOTE: `|rem| < |c| <= 2^(BITS - 1)`, so this always fits.
+            let rem = Self::from_unsigned(rem);
+            let rem = if product_is_negative { rem.wrapping_neg() } else { rem };
+            Some((quo, rem))
+        }
 
         // FIXME: Stabilize when our MSRV goes to `1.67.0+`.
         // /// Returns the base 10 logarithm of the number, rounded down.
@@ -0,0 +1,177 @@
+//! Conversions between signed big integers and floating-point types.
+//!
+//! Conversions to floats round to the nearest representable value, with
+//! ties to even, identical to an `as` cast from a primitive integer.
+//! Conversions from floats always truncate the fractional component, and
+//! then either fail, saturate or wrap if the integral value is out of range.
+
+#[rustfmt::skip]
+macro_rules! define {
+    (unsigned_type => $u_t:ty $(,)?) => {
+        /// Create the integer from the float parts, returning `None` if
+        /// the truncated value cannot be represented by the type.
+        #[inline]
+        const fn checked_from_float_parts(negative: bool, exp: i32, significand: u64) -> Option<Self> {
+            let (magnitude, overflowed) = <$u_t>::overflowing_from_float_parts(exp, significand);
+            let value = magnitude.as_signed();
+            if overflowed {
+                None
+            } else if !negative {
+                match value.is_negative() {
+                    true => None,
+                    false => Some(value),
+                }
+            } else {
+                // NOTE: The only negative value with a magnitude that has the
+                // sign bit set is `MIN`, which negates to itself.
+                let value = value.wrapping_neg();
+                match value.is_positive() {
+                    true => None,
+                    false => Some(value),
+                }
+            }
+        }
+
+        /// Create the integer from the float parts, wrapping around at the
+        /// boundary of the type.
+        #[inline]
+        const fn wrapping_from_float_parts(negative: bool, exp: i32, significand: u64) -> Self {
+            let magnitude = <$u_t>::overflowing_from_float_parts(exp, significand).0;
+            let value = magnitude.as_signed();
+            if negative {
+                value.wrapping_neg()
+            } else {
+                value
+            }
+        }
+
+        /// Converts the integer to the nearest [`f64`], with ties rounding
+        /// to even.
+        ///
+        /// Values too large to be represented are converted to infinity,
+        /// identical to an `as` cast from a primitive integer.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn to_f64(self) -> f64 {
+            // NOTE: Rounding to nearest, ties to even, is symmetric.
+            let value = self.unsigned_abs().to_f64();
+            if self.is_negative() {
+                -value
+            } else {
+                value
+            }
+        }
+
+        /// Converts the integer to the nearest [`f32`], with ties rounding
+        /// to even.
+        ///
+        /// Values too large to be represented are converted to infinity,
+        /// identical to an `as` cast from a primitive integer.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn to_f32(self) -> f32 {
+            let value = self.unsigned_abs().to_f32();
+            if self.is_negative() {
+                -value
+            } else {
+                value
+            }
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component.
+        ///
+        /// Returns `None` if the value is NaN, infinite, or if the truncated
+        /// value cannot be represented by the type.
+        #[inline]
+        pub fn checked_from_f64(value: f64) -> Option<Self> {
+            if !value.is_finite() {
+                return None;
+            }
+            let (negative, exp, significand) = $crate::util::f64_to_parts(value);
+            Self::checked_from_float_parts(negative, exp, significand)
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component.
+        ///
+        /// Returns `None` if the value is NaN, infinite, or if the truncated
+        /// value cannot be represented by the type.
+        #[inline]
+        pub fn checked_from_f32(value: f32) -> Option<Self> {
+            if !value.is_finite() {
+                return None;
+            }
+            let (negative, exp, significand) = $crate::util::f32_to_parts(value);
+            Self::checked_from_float_parts(negative, exp, significand)
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component and saturating at the numeric bounds.
+        ///
+        /// NaN is converted to `0`, identical to an `as` cast to a
+        /// primitive integer.
+        #[inline]
+        pub fn saturating_from_f64(value: f64) -> Self {
+            if value.is_nan() {
+                Self::from_u8(0)
+            } else if let Some(result) = Self::checked_from_f64(value) {
+                result
+            } else if value.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component and saturating at the numeric bounds.
+        ///
+        /// NaN is converted to `0`, identical to an `as` cast to a
+        /// primitive integer.
+        #[inline]
+        pub fn saturating_from_f32(value: f32) -> Self {
+            if value.is_nan() {
+                Self::from_u8(0)
+            } else if let Some(result) = Self::checked_from_f32(value) {
+                result
+            } else if value.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component and wrapping around at the boundary of the type.
+        ///
+        /// This keeps only the low bits of the integral value, as if it
+        /// had been calculated with infinite precision, and then truncated
+        /// to the type. NaN and infinite values are converted to `0`.
+        #[inline]
+        pub fn wrapping_from_f64(value: f64) -> Self {
+            if !value.is_finite() {
+                return Self::from_u8(0);
+            }
+            let (negative, exp, significand) = $crate::util::f64_to_parts(value);
+            Self::wrapping_from_float_parts(negative, exp, significand)
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component and wrapping around at the boundary of the type.
+        ///
+        /// This keeps only the low bits of the integral value, as if it
+        /// had been calculated with infinite precision, and then truncated
+        /// to the type. NaN and infinite values are converted to `0`.
+        #[inline]
+        pub fn wrapping_from_f32(value: f32) -> Self {
+            if !value.is_finite() {
+                return Self::from_u8(0);
+            }
+            let (negative, exp, significand) = $crate::util::f32_to_parts(value);
+            Self::wrapping_from_float_parts(negative, exp, significand)
+        }
+    };
+}
+
+pub(crate) use define;
@@ -173,6 +173,36 @@ macro_rules! define {
         pub fn rem_iwide(self, n: $crate::IWide) -> $crate::IWide {
             self.div_rem_iwide(n).1
         }
+
+        /// Get the quotient of our big integer divided by [`ILimb`][crate::ILimb],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_ilimb(self, n: $crate::ILimb, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_ilimb(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`IWide`][crate::IWide],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_iwide(self, n: $crate::IWide, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_iwide(n), mode)
+        }
     };
 
     (fixed) => {
@@ -450,6 +480,54 @@ macro_rules! define {
         pub fn rem_i128(self, n: i128) -> i128 {
             self.div_rem_i128(n).1
         }
+
+        /// Get the quotient of our big integer divided by [`i32`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_i32(self, n: i32, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_i32(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`i64`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_i64(self, n: i64, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_i64(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`i128`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_i128(self, n: i128, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_i128(n), mode)
+        }
     };
 
     (@wrapping) => {
@@ -23,6 +23,7 @@ pub(crate) mod bitops;
 pub(crate) mod casts;
 pub(crate) mod checked;
 pub(crate) mod constants;
+pub(crate) mod float;
 pub(crate) mod limb;
 pub(crate) mod ops;
 pub(crate) mod overflowing;
@@ -83,6 +84,9 @@ macro_rules! define {
                 see_type => i64,
                 kind => signed,
             );
+            $crate::int::float::define!(
+                unsigned_type => $u_t,
+            );
             $crate::shared::extensions::define!(
                 high_type => $crate::ILimb,
             );
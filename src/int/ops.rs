@@ -69,6 +69,126 @@ macro_rules! define {
             self.wrapping_abs().as_unsigned()
         }
 
+        /// Calculates the complete product `self * rhs` without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. As with the
+        /// primitive signed integers, the low-order bits are unsigned, and the
+        /// sign is carried by the high-order bits.
+        ///
+        /// If you also need to add a carry to the wide result, then you want
+        /// [`Self::carrying_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// Please note that this example is shared between integer types.
+        /// Which explains why `i32` is used here.
+        ///
+        /// ```rust,ignore
+        /// #![feature(bigint_helper_methods)]
+        /// assert_eq!(5i32.widening_mul(-2), (4294967286, -1));
+        /// assert_eq!(1_000_000_000i32.widening_mul(-10), (2884901888, -3));
+        /// ```
+        ///
+        #[doc = $crate::shared::docs::primitive_doc!($see_t, widening_mul)]
+        #[doc = $crate::shared::docs::nightly_doc!()]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn widening_mul(self, rhs: Self) -> ($u_t, Self) {
+            // NOTE: The unsigned product of the two's complement values is off
+            // by `2^BITS` times the other operand for each negative operand,
+            // which only affects the high bits.
+            let (lo, hi) = self.as_unsigned().widening_mul(rhs.as_unsigned());
+            let hi = match self.is_negative() {
+                true => hi.wrapping_sub(rhs.as_unsigned()),
+                false => hi,
+            };
+            let hi = match rhs.is_negative() {
+                true => hi.wrapping_sub(self.as_unsigned()),
+                false => hi,
+            };
+            (lo, Self::from_unsigned(hi))
+        }
+
+        /// Calculates the "full multiplication" `self * rhs + carry`
+        /// without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. As with the
+        /// primitive signed integers, the low-order bits are unsigned, and the
+        /// sign is carried by the high-order bits.
+        ///
+        /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+        /// additional amount of overflow. This allows for chaining together multiple
+        /// multiplications to create "big integers" which represent larger values.
+        ///
+        /// If you don't need the `carry`, then you can use [`Self::widening_mul`] instead.
+        ///
+        #[doc = $crate::shared::docs::primitive_doc!($see_t, carrying_mul)]
+        #[doc = $crate::shared::docs::nightly_doc!()]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn carrying_mul(self, rhs: Self, carry: Self) -> ($u_t, Self) {
+            let (lo, hi) = self.widening_mul(rhs);
+            let (lo, overflowed) = lo.overflowing_add(carry.as_unsigned());
+            // NOTE: The carry is sign-extended, so its high bits are all ones if
+            // it is negative. This cannot overflow, since the magnitude of the
+            // product is at most `2^(2*BITS - 2)`.
+            let hi = hi.wrapping_add_ulimb(overflowed as $crate::ULimb);
+            let hi = match carry.is_negative() {
+                true => hi.wrapping_sub_ulimb(1),
+                false => hi,
+            };
+            (lo, hi)
+        }
+
+        /// Calculates the complete product `self * n` of the big integer and
+        /// an [`ILimb`][crate::ILimb] without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order
+        /// (overflow) limb of the result as two separate values, in that order.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn widening_mul_ilimb(self, n: $crate::ILimb) -> ($u_t, $crate::ILimb) {
+            // NOTE: Multiply the magnitudes, keeping the carry out of the last
+            // limb, and then negate the `LIMBS + 1` limb product if needed.
+            let x = self.unsigned_abs().to_le_limbs();
+            let y = n.unsigned_abs() as $crate::UWide;
+            let mut lo = [0; Self::LIMBS];
+            let mut carry: $crate::ULimb = 0;
+            let mut i = 0;
+            while i < Self::LIMBS {
+                let v = (x[i] as $crate::UWide) * y + (carry as $crate::UWide);
+                lo[i] = v as $crate::ULimb;
+                carry = (v >> $crate::ULimb::BITS) as $crate::ULimb;
+                i += 1;
+            }
+
+            let lo = <$u_t>::from_le_limbs(lo);
+            if self.is_negative() == (n < 0) {
+                (lo, carry as $crate::ILimb)
+            } else {
+                let hi = (!carry).wrapping_add(lo.eq_const(<$u_t>::MIN) as $crate::ULimb);
+                (lo.wrapping_neg(), hi as $crate::ILimb)
+            }
+        }
+
+        /// Calculates the complete square `self * self` without the
+        /// possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order
+        /// (overflow) bits of the result as two separate values, in that
+        /// order. The square is never negative, so the high bits always
+        /// fit in the signed type.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn widening_square(self) -> ($u_t, Self) {
+            let (lo, hi) = self.unsigned_abs().widening_square();
+            (lo, Self::from_unsigned(hi))
+        }
+
         /// Calculates the quotient of Euclidean division of `self` by `rhs`.
         ///
         /// This computes the integer `q` such that `self = q * rhs + r`, with
@@ -166,6 +286,76 @@ macro_rules! define {
             }
         }
 
+        // If the truncated quotient `quo`, with the non-zero remainder `rem`,
+        // should be rounded away from zero.
+        #[inline(always)]
+        fn is_round_away(self, rhs: Self, quo: Self, rem: Self, mode: $crate::RoundingMode) -> bool {
+            let urem = rem.unsigned_abs();
+            let half = urem.cmp(&rhs.unsigned_abs().wrapping_sub(urem));
+            let is_negative = self.is_negative() ^ rhs.is_negative();
+            mode.is_away(is_negative, half, quo.least_significant_limb() & 1 != 0)
+        }
+
+        /// Calculates the quotient of `self` and `rhs`, rounding the result
+        /// with `mode`.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_signed_doc!()]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round(self, rhs: Self, mode: $crate::RoundingMode) -> Self {
+            self.div_rem_round(rhs, mode).0
+        }
+
+        /// Calculates the quotient and remainder of `self` and `rhs`, rounding
+        /// the quotient with `mode`.
+        ///
+        /// The remainder is `self - quotient * rhs`, which is always smaller
+        /// in magnitude than `rhs`, but may have either sign.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_signed_doc!()]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_rem_round(self, rhs: Self, mode: $crate::RoundingMode) -> (Self, Self) {
+            let (quo, rem) = self.div_rem(rhs);
+            if rem.eq_const(Self::from_u8(0)) || !self.is_round_away(rhs, quo, rem, mode) {
+                (quo, rem)
+            } else if self.is_negative() ^ rhs.is_negative() {
+                // NOTE: This can't overflow, since `abs(rhs) > 1` if the remainder is non-zero.
+                (quo.wrapping_sub_ulimb(1), rem.wrapping_add(rhs))
+            } else {
+                (quo.wrapping_add_ulimb(1), rem.wrapping_sub(rhs))
+            }
+        }
+
+        /// Rounds `self` to a multiple of `rhs` with `mode`.
+        ///
+        /// The direction of `mode` is relative to `self`, not the quotient, so
+        /// the result is the same for `rhs` and `-rhs`. For example, [`Down`]
+        /// always gives the largest multiple less than or equal to `self`.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        #[doc = $crate::shared::docs::overflow_assertions_doc!()]
+        ///
+        /// [`Down`]: crate::RoundingMode::Down
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn round_to_multiple_of(self, rhs: Self, mode: $crate::RoundingMode) -> Self {
+            use core::ops::Sub;
+
+            if rhs.eq_const(Self::from_i8(-1)) {
+                return self;
+            }
+
+            // NOTE: If `rhs` is negative, a larger quotient is a smaller multiple.
+            let mode = if rhs.is_negative() {
+                mode.reverse()
+            } else {
+                mode
+            };
+            self.sub(self.div_rem_round(rhs, mode).1)
+        }
+
         /// If `rhs` is positive, calculates the smallest value greater than or
         /// equal to `self` that is a multiple of `rhs`. If `rhs` is negative,
         /// calculates the largest value less than or equal to `self` that is a
@@ -200,19 +390,230 @@ macro_rules! define {
             }
         }
 
-        // FIXME: Stabilize when our MSRV goes to `1.84.0+`.
-        // /// Returns the square root of the number, rounded down.
-        // ///
-        // /// # Panics
-        // ///
-        // /// This function will panic if `self` is negative.
-        // #[inline]
-        // pub const fn isqrt(self) -> Self {
-        //     match self.checked_isqrt() {
-        //         Some(sqrt) => sqrt,
-        //         None => core::panic!("argument of integer square root cannot be
-        // negative"),     }
-        // }
+        /// Returns the square root of the number, rounded down.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `self` is negative.
+        ///
+        #[doc = $crate::shared::docs::primitive_doc!($see_t, isqrt)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn isqrt(self) -> Self {
+            match self.checked_isqrt() {
+                Some(sqrt) => sqrt,
+                None => core::panic!("argument of integer square root cannot be negative"),
+            }
+        }
+
+        /// Returns the square root of the number, rounded down, which can be
+        /// used in `const` contexts.
+        ///
+        /// This uses binary long division for each Newton iteration, which is
+        /// much slower than [`isqrt`] at runtime, so it should only be used
+        /// for constants.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `self` is negative.
+        ///
+        /// [`isqrt`]: Self::isqrt
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn isqrt_const(self) -> Self {
+            match self.is_negative() {
+                true => core::panic!("argument of integer square root cannot be negative"),
+                // NOTE: The root of a non-negative value is always less than `MAX`.
+                false => self.as_unsigned().isqrt_const().as_signed(),
+            }
+        }
+
+        /// Returns the `n`th root of the number, rounded toward zero.
+        ///
+        /// Odd roots of negative numbers are negative, such that
+        /// `(-self).iroot(n) == -(self.iroot(n))`.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `n` is zero, or if `self` is negative
+        /// and `n` is even.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn iroot(self, n: u32) -> Self {
+            assert!(n != 0, "degree of integer root must be positive");
+            match self.checked_iroot(n) {
+                Some(root) => root,
+                None => core::panic!("argument of even integer root cannot be negative"),
+            }
+        }
+
+        /// Returns the cube root of the number, rounded toward zero.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn icbrt(self) -> Self {
+            self.iroot(3)
+        }
+
+        /// Returns the base and largest exponent if the number is a perfect
+        /// power, that is, `self == base.pow(exponent)` with `exponent >= 2`.
+        ///
+        /// Negative numbers can only be odd powers of a negative base.
+        /// Returns `None` if the number is not a perfect power. `-1`, `0`
+        /// and `1` are a perfect power of infinitely many exponents, and
+        /// return the smallest valid exponent.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn is_perfect_power(self) -> Option<(Self, u32)> {
+            if !self.is_negative() {
+                let (base, exp) = self.as_unsigned().is_perfect_power()?;
+                return Some((base.as_signed(), exp));
+            } else if self.eq_const(Self::from_i8(-1)) {
+                return Some((self, 3));
+            }
+
+            // NOTE: The base must be smaller than the magnitude, so it cannot
+            // be `MIN`, and only the odd part of the exponent can be kept.
+            let (base, exp) = self.unsigned_abs().is_perfect_power()?;
+            let zeros = exp.trailing_zeros();
+            let odd = exp >> zeros;
+            match odd {
+                1 => None,
+                _ => Some((base.wrapping_pow(1 << zeros).as_signed().wrapping_neg(), odd)),
+            }
+        }
+
+        /// Computes the greatest common divisor of `self` and `rhs`.
+        ///
+        /// The GCD is always non-negative, so this returns the unsigned type
+        /// to handle `MIN`, whose magnitude cannot be represented in `Self`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn gcd(self, rhs: Self) -> $u_t {
+            self.unsigned_abs().gcd(rhs.unsigned_abs())
+        }
+
+        /// Computes the least common multiple of `self` and `rhs`.
+        ///
+        /// The LCM is always non-negative, so this returns the unsigned type.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if the result overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn lcm(self, rhs: Self) -> $u_t {
+            self.unsigned_abs().lcm(rhs.unsigned_abs())
+        }
+
+        /// Computes the greatest common divisor of `self` and `rhs`, and the
+        /// Bezout coefficients `x` and `y`, such that `self * x + rhs * y == gcd`.
+        ///
+        /// The GCD is always non-negative, so this returns the unsigned type.
+        /// The coefficients are the minimal pair for the magnitudes, so they
+        /// always fit in `Self`. If `rhs` is `0`, the coefficients are `(±1, 0)`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn extended_gcd(self, rhs: Self) -> ($u_t, Self, Self) {
+            let (gcd, x, y) = self.unsigned_abs().extended_gcd(rhs.unsigned_abs());
+            let x = match self.is_negative() {
+                true => x.wrapping_neg(),
+                false => x,
+            };
+            let y = match rhs.is_negative() {
+                true => y.wrapping_neg(),
+                false => y,
+            };
+            (gcd, x, y)
+        }
+
+        /// Calculates the Jacobi symbol `(self / n)`.
+        ///
+        /// This is `0` if `self` and `n` are not coprime, and otherwise `1`
+        /// or `-1`. For a negative `self`, this uses `(-1 / n) == -1` if
+        /// and only if `n == 3 mod 4`.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `n` is negative or even.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn jacobi(self, n: Self) -> i32 {
+            assert!(!n.is_negative(), "Jacobi symbol requires a positive denominator");
+            let result = self.unsigned_abs().jacobi(n.unsigned_abs());
+            match self.is_negative() && n.as_u32() % 4 == 3 {
+                true => -result,
+                false => result,
+            }
+        }
+
+        /// Calculates the Legendre symbol `(self / p)` for an odd prime `p`.
+        ///
+        /// This is `0` if `p` divides `self`, `1` if `self` is a quadratic
+        /// residue modulo `p`, and `-1` otherwise. `p` is not checked for
+        /// primality, and this is identical to the [`jacobi`] symbol.
+        ///
+        /// [`jacobi`]: Self::jacobi
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `p` is negative or even.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn legendre(self, p: Self) -> i32 {
+            self.jacobi(p)
+        }
+
+        /// Calculates the quotient and remainder of `self` and `rhs`, which can
+        /// be used in `const` contexts.
+        ///
+        /// This rounds the quotient towards zero, like `self / rhs`, and uses
+        /// binary long division, which is much slower than [`div_rem`] at
+        /// runtime, so it should only be used for constants.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_signed_doc!()]
+        ///
+        /// [`div_rem`]: Self::div_rem
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn div_rem_const(self, rhs: Self) -> (Self, Self) {
+            if self.eq_const(Self::MIN) && rhs.eq_const(Self::from_i8(-1)) {
+                core::panic!("attempt to divide with overflow");
+            }
+            let (div, rem) = self.unsigned_abs().div_rem_const(rhs.unsigned_abs());
+            let div = match self.is_negative() != rhs.is_negative() {
+                true => div.as_signed().wrapping_neg(),
+                false => div.as_signed(),
+            };
+            let rem = match self.is_negative() {
+                true => rem.as_signed().wrapping_neg(),
+                false => rem.as_signed(),
+            };
+            (div, rem)
+        }
+
+        /// Calculates the quotient of `self` and `rhs`, which can be used in
+        /// `const` contexts. See [`div_rem_const`].
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_signed_doc!()]
+        ///
+        /// [`div_rem_const`]: Self::div_rem_const
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn div_const(self, rhs: Self) -> Self {
+            self.div_rem_const(rhs).0
+        }
+
+        /// Calculates the remainder of `self` and `rhs`, which can be used in
+        /// `const` contexts. See [`div_rem_const`].
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_signed_doc!()]
+        ///
+        /// [`div_rem_const`]: Self::div_rem_const
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn rem_const(self, rhs: Self) -> Self {
+            self.div_rem_const(rhs).1
+        }
 
         /// Returns the logarithm of the number with respect to an arbitrary base,
         /// rounded down.
@@ -335,6 +736,67 @@ macro_rules! define {
             let is_negative = Self::from_u8(t.is_negative() as u8);
             t.wrapping_add(is_negative.bitand_const(xor))
         }
+
+        /// Calculates `self * b / c`, rounded toward zero.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div(self, b: Self, c: Self) -> Self {
+            self.mul_div_rem(b, c).0
+        }
+
+        /// Calculates the quotient and remainder of `self * b / c`.
+        ///
+        /// The quotient is rounded toward zero, so the remainder has the
+        /// same sign as `self * b`, like the `/` and `%` operators. The
+        /// product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div_rem(self, b: Self, c: Self) -> (Self, Self) {
+            assert!(!c.eq_const(Self::from_u8(0)), "attempt to divide by zero");
+            match self.checked_mul_div_rem(b, c) {
+                Some(v) => v,
+                None => core::panic!("attempt to multiply with overflow"),
+            }
+        }
+
+        /// Calculates `self * b / c`, rounded toward positive infinity.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div_ceil(self, b: Self, c: Self) -> Self {
+            let (quo, rem) = self.mul_div_rem(b, c);
+            // NOTE: The exact quotient is positive if the remainder, which has the
+            // sign of `self * b`, has the same sign as `c`.
+            if rem.eq_const(Self::from_u8(0)) || rem.is_negative() != c.is_negative() {
+                quo
+            } else {
+                match quo.checked_add(Self::from_u8(1)) {
+                    Some(v) => v,
+                    None => core::panic!("attempt to multiply with overflow"),
+                }
+            }
+        }
     };
 }
 
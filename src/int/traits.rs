@@ -245,13 +245,13 @@ macro_rules! define {
             }
 
             #[inline(always)]
-            fn from_f32(_: f32) -> Option<Self> {
-                unimplemented!("floating-point conversions")
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::checked_from_f32(n)
             }
 
             #[inline(always)]
-            fn from_f64(_: f64) -> Option<Self> {
-                unimplemented!("floating-point conversions")
+            fn from_f64(n: f64) -> Option<Self> {
+                Self::checked_from_f64(n)
             }
         }
 
@@ -391,12 +391,12 @@ macro_rules! define {
 
             #[inline(always)]
             fn to_f32(&self) -> Option<f32> {
-                unimplemented!("floating-point conversions")
+                Some(Self::to_f32(*self))
             }
 
             #[inline(always)]
             fn to_f64(&self) -> Option<f64> {
-                unimplemented!("floating-point conversions")
+                Some(Self::to_f64(*self))
             }
         }
 
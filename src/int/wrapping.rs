@@ -63,6 +63,17 @@ macro_rules! define {
             Self::from_ne_limbs(limbs)
         }
 
+        /// Wrapping (modular) squaring. Computes `self * self`, wrapping
+        /// around at the boundary of the type.
+        ///
+        /// The low bits of the square are the same for the two's complement
+        /// representation, so this uses the unsigned squaring.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn wrapping_square(self) -> Self {
+            Self::from_unsigned(self.as_unsigned().wrapping_square())
+        }
+
         /// Div/Rem operation on the integer.
         ///
         /// This allows storing of both the quotient and remainder without
@@ -30,12 +30,12 @@
 //! for [`u32`], [`u64`], and [`u128`], guaranteeing API stability across all
 //! platforms.
 //! - `num-traits`: Implement traits from the [`num-traits`] crate for all
-//!   integer types provided by this crate.  Floating-point conversion methods
-//!   are unimplemented and will panic.  The `NumCast` trait and `cast`
+//!   integer types provided by this crate.  The `NumCast` trait and `cast`
 //!   function, by their nature, will not be able cast values above what's
 //!   supported by [`u128`] or [`i128`], even when casting between types
-//!   supplied by this crate.  All other traits and methods from [`num-traits`]
-//!   will behave as expected.
+//!   supplied by this crate: use the `checked_from_*` methods or [`TryFrom`]
+//!   for those instead.  All other traits and methods from [`num-traits`] will
+//!   behave as expected.
 //!
 //! If you need larger integers, [`crypto-bigint`] has high-performance
 //! addition, subtraction, and multiplication. With integers with a large
@@ -104,9 +104,17 @@
 #[macro_use]
 mod util;
 
+mod barrett;
+mod convert;
+mod divider;
 mod error;
+mod factors;
+mod fp;
 mod int;
+mod mixed;
+mod montgomery;
 mod parse;
+mod rounding;
 mod shared;
 mod types;
 mod uint;
@@ -115,7 +123,13 @@ mod write;
 // exposed only for testing
 pub mod math;
 
+pub use barrett::Barrett;
+pub use divider::Divider;
 pub use error::{IntErrorKind, ParseIntError, TryFromIntError};
+pub use factors::Factors;
+pub use fp::{Fp, Modulus};
+pub use montgomery::Montgomery;
+pub use rounding::RoundingMode;
 pub use types::{ILimb, IWide, ULimb, UWide};
 
 /// Define a new signed and unsigned integer pair
@@ -180,6 +194,246 @@ define!(
     bits => 1024,
 );
 
+#[cfg(feature = "i512")]
+barrett::define!(type => U256, wide_type => U512);
+#[cfg(all(feature = "i512", feature = "i1024"))]
+barrett::define!(type => U512, wide_type => U1024);
+
+#[cfg(feature = "i512")]
+convert::halves!(narrow => (U256, I256), wide => (U512, I512));
+#[cfg(all(feature = "i512", feature = "i1024"))]
+convert::halves!(narrow => (U512, I512), wide => (U1024, I1024));
+
+#[cfg(feature = "i384")]
+mixed::define! {
+    type => U384,
+    rhs => U256,
+    wrapping_add => wrapping_add_u256,
+    overflowing_add => overflowing_add_u256,
+    wrapping_sub => wrapping_sub_u256,
+    overflowing_sub => overflowing_sub_u256,
+    wrapping_mul => wrapping_mul_u256,
+    overflowing_mul => overflowing_mul_u256,
+    div_rem => div_rem_u256,
+    div => div_u256,
+    rem => rem_u256,
+}
+#[cfg(feature = "i512")]
+mixed::define! {
+    type => U512,
+    rhs => U256,
+    wrapping_add => wrapping_add_u256,
+    overflowing_add => overflowing_add_u256,
+    wrapping_sub => wrapping_sub_u256,
+    overflowing_sub => overflowing_sub_u256,
+    wrapping_mul => wrapping_mul_u256,
+    overflowing_mul => overflowing_mul_u256,
+    div_rem => div_rem_u256,
+    div => div_u256,
+    rem => rem_u256,
+}
+#[cfg(all(feature = "i512", feature = "i384"))]
+mixed::define! {
+    type => U512,
+    rhs => U384,
+    wrapping_add => wrapping_add_u384,
+    overflowing_add => overflowing_add_u384,
+    wrapping_sub => wrapping_sub_u384,
+    overflowing_sub => overflowing_sub_u384,
+    wrapping_mul => wrapping_mul_u384,
+    overflowing_mul => overflowing_mul_u384,
+    div_rem => div_rem_u384,
+    div => div_u384,
+    rem => rem_u384,
+}
+#[cfg(feature = "i1024")]
+mixed::define! {
+    type => U1024,
+    rhs => U256,
+    wrapping_add => wrapping_add_u256,
+    overflowing_add => overflowing_add_u256,
+    wrapping_sub => wrapping_sub_u256,
+    overflowing_sub => overflowing_sub_u256,
+    wrapping_mul => wrapping_mul_u256,
+    overflowing_mul => overflowing_mul_u256,
+    div_rem => div_rem_u256,
+    div => div_u256,
+    rem => rem_u256,
+}
+#[cfg(all(feature = "i1024", feature = "i384"))]
+mixed::define! {
+    type => U1024,
+    rhs => U384,
+    wrapping_add => wrapping_add_u384,
+    overflowing_add => overflowing_add_u384,
+    wrapping_sub => wrapping_sub_u384,
+    overflowing_sub => overflowing_sub_u384,
+    wrapping_mul => wrapping_mul_u384,
+    overflowing_mul => overflowing_mul_u384,
+    div_rem => div_rem_u384,
+    div => div_u384,
+    rem => rem_u384,
+}
+#[cfg(all(feature = "i1024", feature = "i512"))]
+mixed::define! {
+    type => U1024,
+    rhs => U512,
+    wrapping_add => wrapping_add_u512,
+    overflowing_add => overflowing_add_u512,
+    wrapping_sub => wrapping_sub_u512,
+    overflowing_sub => overflowing_sub_u512,
+    wrapping_mul => wrapping_mul_u512,
+    overflowing_mul => overflowing_mul_u512,
+    div_rem => div_rem_u512,
+    div => div_u512,
+    rem => rem_u512,
+}
+
+#[cfg(feature = "i384")]
+convert::define! {
+    type => U256,
+    from => U384, impl => TryFrom, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => TryFrom, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(feature = "i512")]
+convert::define! {
+    type => U256,
+    from => U512, impl => TryFrom, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => TryFrom, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+#[cfg(feature = "i1024")]
+convert::define! {
+    type => U256,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i384")]
+convert::define! {
+    type => I256,
+    from => U384, impl => TryFrom, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => TryFrom, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(feature = "i512")]
+convert::define! {
+    type => I256,
+    from => U512, impl => TryFrom, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => TryFrom, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+#[cfg(feature = "i1024")]
+convert::define! {
+    type => I256,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i384")]
+convert::define! {
+    type => U384,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => TryFrom, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i384", feature = "i512"))]
+convert::define! {
+    type => U384,
+    from => U512, impl => TryFrom, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => TryFrom, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+#[cfg(all(feature = "i384", feature = "i1024"))]
+convert::define! {
+    type => U384,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i384")]
+convert::define! {
+    type => I384,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => From, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i384", feature = "i512"))]
+convert::define! {
+    type => I384,
+    from => U512, impl => TryFrom, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => TryFrom, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+#[cfg(all(feature = "i384", feature = "i1024"))]
+convert::define! {
+    type => I384,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i512")]
+convert::define! {
+    type => U512,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => TryFrom, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i512", feature = "i384"))]
+convert::define! {
+    type => U512,
+    from => U384, impl => From, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => TryFrom, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(all(feature = "i512", feature = "i1024"))]
+convert::define! {
+    type => U512,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i512")]
+convert::define! {
+    type => I512,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => From, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i512", feature = "i384"))]
+convert::define! {
+    type => I512,
+    from => U384, impl => From, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => From, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(all(feature = "i512", feature = "i1024"))]
+convert::define! {
+    type => I512,
+    from => U1024, impl => TryFrom, wrapping => wrapping_from_u1024, saturating => saturating_from_u1024, checked => checked_from_u1024;
+    from => I1024, impl => TryFrom, wrapping => wrapping_from_i1024, saturating => saturating_from_i1024, checked => checked_from_i1024;
+}
+#[cfg(feature = "i1024")]
+convert::define! {
+    type => U1024,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => TryFrom, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i1024", feature = "i384"))]
+convert::define! {
+    type => U1024,
+    from => U384, impl => From, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => TryFrom, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(all(feature = "i1024", feature = "i512"))]
+convert::define! {
+    type => U1024,
+    from => U512, impl => From, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => TryFrom, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+#[cfg(feature = "i1024")]
+convert::define! {
+    type => I1024,
+    from => U256, impl => From, wrapping => wrapping_from_u256, saturating => saturating_from_u256, checked => checked_from_u256;
+    from => I256, impl => From, wrapping => wrapping_from_i256, saturating => saturating_from_i256, checked => checked_from_i256;
+}
+#[cfg(all(feature = "i1024", feature = "i384"))]
+convert::define! {
+    type => I1024,
+    from => U384, impl => From, wrapping => wrapping_from_u384, saturating => saturating_from_u384, checked => checked_from_u384;
+    from => I384, impl => From, wrapping => wrapping_from_i384, saturating => saturating_from_i384, checked => checked_from_i384;
+}
+#[cfg(all(feature = "i1024", feature = "i512"))]
+convert::define! {
+    type => I1024,
+    from => U512, impl => From, wrapping => wrapping_from_u512, saturating => saturating_from_u512, checked => checked_from_u512;
+    from => I512, impl => From, wrapping => wrapping_from_i512, saturating => saturating_from_i512, checked => checked_from_i512;
+}
+
 /// The 256-bit unsigned integer type.
 pub type u256 = U256;
 
@@ -413,6 +667,753 @@ mod u256_tests {
     fn from_str_radix_neg_test() {
         _ = u256::from_str_radix("-123", 10).unwrap();
     }
+
+    #[test]
+    fn float_test() {
+        assert_eq!(u256::from_u8(0).to_f64(), 0.0);
+        assert_eq!(u256::from_u128(u128::MAX).to_f64(), u128::MAX as f64);
+        assert_eq!(u256::MAX.to_f64(), 1.157920892373162e77);
+        assert_eq!(u256::MAX.to_f32(), f32::INFINITY);
+        // 2^128 + 2^75 is exactly halfway, and must round down to even.
+        let halfway = u256::from_le_u64([0, 1 << 11, 1, 0]);
+        assert_eq!(halfway.to_f64(), 2.0f64.powi(128));
+        assert_eq!(halfway.wrapping_add_ulimb(1).to_f64(), 2.0f64.powi(128) + 2.0f64.powi(76));
+
+        assert_eq!(u256::checked_from_f64(1.5), Some(u256::from_u8(1)));
+        assert_eq!(u256::checked_from_f64(-0.5), Some(u256::from_u8(0)));
+        assert_eq!(u256::checked_from_f64(-1.0), None);
+        assert_eq!(u256::checked_from_f64(f64::NAN), None);
+        assert_eq!(u256::checked_from_f64(2.0f64.powi(256)), None);
+        assert_eq!(u256::checked_from_f64(2.0f64.powi(255)), Some(u256::from_u8(1) << 255));
+        assert_eq!(u256::checked_from_f32(f32::MAX), Some(u256::from_u128(f32::MAX as u128)));
+        assert_eq!(u256::saturating_from_f64(f64::INFINITY), u256::MAX);
+        assert_eq!(u256::saturating_from_f64(-2.0), u256::MIN);
+        assert_eq!(u256::saturating_from_f64(f64::NAN), u256::MIN);
+        assert_eq!(u256::wrapping_from_f64(-1.0), u256::MAX);
+        assert_eq!(
+            u256::wrapping_from_f64(2.0f64.powi(256) + 2.0f64.powi(204)),
+            u256::from_u8(1) << 204
+        );
+        assert_eq!(u256::wrapping_from_f32(3.75), u256::from_u8(3));
+    }
+
+    #[test]
+    fn isqrt_test() {
+        assert_eq!(u256::from_u8(0).isqrt(), u256::from_u8(0));
+        assert_eq!(u256::from_u8(24).isqrt(), u256::from_u8(4));
+        assert_eq!(u256::MAX.isqrt(), u256::from_u128(u128::MAX));
+        let root = u256::from_u128(u128::MAX);
+        assert_eq!((root * root).isqrt(), root);
+        assert_eq!((root * root - u256::from_u8(1)).isqrt(), root - u256::from_u8(1));
+
+        const ROOT: u256 = u256::MAX.isqrt_const();
+        const SMALL_ROOT: u256 = u256::from_u8(24).isqrt_const();
+        const SIGNED_ROOT: i256 = i256::from_u8(49).isqrt_const();
+        assert_eq!(ROOT, u256::from_u128(u128::MAX));
+        assert_eq!(SMALL_ROOT, u256::from_u8(4));
+        assert_eq!(SIGNED_ROOT, i256::from_u8(7));
+        assert_eq!((root * root - u256::from_u8(1)).isqrt_const(), root - u256::from_u8(1));
+
+        #[cfg(feature = "i1024")]
+        {
+            assert_eq!(U1024::MAX.isqrt(), U1024::MAX >> 512);
+            let root = I1024::MAX.isqrt();
+            let next = root + I1024::from_u8(1);
+            assert!(root.checked_mul(root).is_some());
+            assert!(next.checked_mul(next).is_none());
+        }
+    }
+
+    #[test]
+    fn square_test() {
+        let values = [
+            u256::from_u8(0),
+            u256::from_u8(3),
+            u256::from_u64(u64::MAX),
+            u256::from_u128(u128::MAX),
+            u256::from_u128(u128::MAX) + u256::from_u8(1),
+            u256::MAX.wrapping_shr(7),
+            u256::MAX,
+        ];
+        for &x in values.iter() {
+            assert_eq!(x.wrapping_square(), x.wrapping_mul(x));
+            assert_eq!(x.overflowing_square(), x.overflowing_mul(x));
+            assert_eq!(x.checked_square(), x.checked_mul(x));
+            assert_eq!(x.widening_square(), x.widening_mul(x));
+
+            let root = x.isqrt();
+            let (lo, hi) = root.widening_square();
+            assert!(hi == u256::from_u8(0) && lo <= x);
+            let (lo, hi) = (root + u256::from_u8(1)).widening_square();
+            assert!(hi != u256::from_u8(0) || lo > x);
+        }
+        assert_eq!(u256::from_u128(u128::MAX).square(), u256::from_u128(u128::MAX).pow(2));
+        assert_eq!(
+            u256::from_u8(3).wrapping_pow(161),
+            u256::from_u8(3).wrapping_mul(u256::from_u8(3).pow(160))
+        );
+        assert_eq!(u256::from_u8(3).checked_pow(162), None);
+
+        #[cfg(feature = "i1024")]
+        {
+            let x = U1024::MAX.wrapping_shr(3);
+            assert_eq!(x.widening_square(), x.widening_mul(x));
+            assert_eq!(x.wrapping_square(), x.wrapping_mul(x));
+        }
+    }
+
+    #[test]
+    fn iroot_test() {
+        assert_eq!(u256::from_u8(64).icbrt(), u256::from_u8(4));
+        assert_eq!(u256::from_u8(63).icbrt(), u256::from_u8(3));
+        assert_eq!(u256::MAX.icbrt(), u256::from_u128(0x28_5145_f31a_e515_c447_bb56));
+        assert_eq!(u256::from_u8(81).is_perfect_power(), Some((u256::from_u8(3), 4)));
+
+        #[cfg(feature = "i1024")]
+        {
+            let base = U1024::from_u8(10);
+            assert_eq!(base.pow(300).is_perfect_power(), Some((base, 300)));
+            assert_eq!(base.pow(300).iroot(150), U1024::from_u8(100));
+            assert_eq!(I1024::from_i8(-10).pow(301).iroot(301), I1024::from_i8(-10));
+        }
+    }
+
+    #[test]
+    fn modular_test() {
+        // 2^255 - 19
+        let p = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        assert_eq!(u256::MAX.add_mod(u256::MAX, p), u256::from_u8(0x4a));
+        assert_eq!(u256::from_u8(1).sub_mod(u256::from_u8(2), p), p.wrapping_sub(u256::from_u8(1)));
+        assert_eq!(u256::MAX.mul_mod(u256::MAX, p), u256::from_u16(0x559));
+        assert_eq!(u256::from_u8(3).pow_mod(u256::MAX, p), u256::from_u64(0x383d9170b85ff80b));
+        assert_eq!(u256::from_u8(3).pow_mod(p.wrapping_sub(u256::from_u8(1)), p), u256::from_u8(1));
+        assert_eq!(u256::from_u8(5).pow_mod(u256::from_u8(0), u256::from_u8(1)), u256::from_u8(0));
+
+        // requires adding back the divisor in the long division
+        let x = u256::MAX.wrapping_shl(129).wrapping_add(u256::from_u128(u128::MAX));
+        let m = u256::from_u128(u128::MAX).wrapping_add(u256::from_u8(2));
+        assert_eq!(x.mul_mod(u256::from_u128(u128::MAX), m), u256::from_u128(u128::MAX));
+
+        let inv = u256::MAX.inv_mod(p);
+        assert_eq!(inv.map(|x| x.mul_mod(u256::MAX, p)), Some(u256::from_u8(1)));
+        assert_eq!(u256::from_u8(6).inv_mod(u256::from_u8(9)), None);
+        assert_eq!(u256::from_u8(3).inv_mod(u256::from_u8(7)), Some(u256::from_u8(5)));
+    }
+
+    #[test]
+    fn jacobi_test() {
+        assert_eq!(u256::from_u8(0).jacobi(u256::from_u8(1)), 1);
+        assert_eq!(u256::from_u8(0).jacobi(u256::from_u8(3)), 0);
+        assert_eq!(u256::from_u8(2).jacobi(u256::from_u8(7)), 1);
+        assert_eq!(u256::from_u8(8).jacobi(u256::from_u8(21)), -1);
+        assert_eq!(u256::from_u16(1001).jacobi(u256::from_u16(9907)), -1);
+        assert_eq!(u256::from_u8(30).jacobi(u256::from_u8(57)), 0);
+        assert_eq!(u256::from_u8(19).jacobi(u256::from_u8(45)), 1);
+        assert_eq!(u256::MAX.jacobi(u256::MAX), 0);
+
+        // 2^255 - 19, where `-1` is a residue and `2` is not.
+        let p = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        assert_eq!(p.wrapping_sub(u256::from_u8(1)).legendre(p), 1);
+        assert_eq!(u256::from_u8(2).legendre(p), -1);
+        assert_eq!(u256::MAX.legendre(p), u256::from_u8(0x25).legendre(p));
+    }
+
+    #[test]
+    fn sqrt_mod_test() {
+        let check = |p: u256| {
+            for x in [2u64, 3, 5, 7, 0x1234_5678_9abc_def0, u64::MAX] {
+                let x = u256::from_u64(x);
+                let square = x.mul_mod(x, p);
+                let root = square.sqrt_mod(p);
+                assert!(root == Some(x) || root == Some(p.wrapping_sub(x)));
+                assert_eq!(x.sqrt_mod(p).is_some(), x.legendre(p) == 1);
+            }
+            assert_eq!(u256::from_u8(0).sqrt_mod(p), Some(u256::from_u8(0)));
+            assert_eq!(p.sqrt_mod(p), Some(u256::from_u8(0)));
+        };
+
+        // secp256k1, `p == 3 mod 4`
+        check(u256::MAX.wrapping_sub(u256::from_u64(0x1_0000_03d0)));
+        // 2^255 - 19, `p == 5 mod 8`
+        check(u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18)));
+        // BLS12-381 scalar field, `p == 1 mod 2^32`
+        check(u256::from_le_u64([
+            0xffffffff00000001,
+            0x53bda402fffe5bfe,
+            0x3339d80809a1d805,
+            0x73eda753299d7d48,
+        ]));
+        // NIST P-224, `p == 1 mod 2^96`
+        let p224 =
+            u256::from_u8(1).wrapping_shl(224).wrapping_sub(u256::from_u8(1).wrapping_shl(96));
+        check(p224.wrapping_add(u256::from_u8(1)));
+
+        assert_eq!(u256::from_u8(3).sqrt_mod(u256::from_u8(2)), Some(u256::from_u8(1)));
+        assert_eq!(
+            u256::from_u8(2).sqrt_mod(u256::from_u8(17)).map(|x| x.mul_mod(x, u256::from_u8(17))),
+            Some(u256::from_u8(2))
+        );
+        assert_eq!(u256::from_u8(3).sqrt_mod(u256::from_u8(17)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sqrt_mod_even_test() {
+        _ = u256::from_u8(1).sqrt_mod(u256::from_u8(6));
+    }
+
+    #[test]
+    fn montgomery_test() {
+        // 2^255 - 19
+        let p = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        let ctx = Montgomery::<u256>::new(p);
+        assert_eq!(ctx.modulus(), p);
+        assert_eq!(ctx.one(), u256::from_u8(0x26));
+        assert_eq!(ctx.to_mont(u256::from_u8(1)), u256::from_u8(0x26));
+        assert_eq!(ctx.to_mont(u256::MAX), ctx.to_mont(u256::from_u8(0x25)));
+        assert_eq!(ctx.from_mont(ctx.one()), u256::from_u8(1));
+
+        let x = ctx.to_mont(u256::MAX);
+        let y = ctx.to_mont(u256::from_u8(3));
+        assert_eq!(ctx.from_mont(ctx.mul(x, x)), u256::MAX.mul_mod(u256::MAX, p));
+        assert_eq!(ctx.square(x), ctx.mul(x, x));
+        assert_eq!(ctx.from_mont(ctx.pow(y, u256::MAX)), u256::from_u64(0x383d9170b85ff80b));
+        assert_eq!(ctx.pow(y, u256::from_u8(0)), ctx.one());
+
+        assert_eq!(Montgomery::<u256>::checked_new(u256::from_u8(10)), None);
+        let ctx = Montgomery::<u256>::new(u256::from_u8(1));
+        assert_eq!(
+            ctx.from_mont(ctx.pow(ctx.to_mont(u256::from_u8(5)), u256::from_u8(0))),
+            u256::MIN
+        );
+
+        #[cfg(feature = "i1024")]
+        {
+            // 2^521 - 1
+            let p = U1024::from_u8(1).wrapping_shl(521).wrapping_sub(U1024::from_u8(1));
+            let ctx = Montgomery::<U1024>::new(p);
+            let x = ctx.to_mont(U1024::from_u8(3));
+            assert_eq!(
+                ctx.from_mont(ctx.pow(x, p.wrapping_sub(U1024::from_u8(1)))),
+                U1024::from_u8(1)
+            );
+        }
+    }
+
+    #[test]
+    fn fp_test() {
+        struct Secp256k1;
+        impl Modulus for Secp256k1 {
+            const MODULUS: u256 = u256::MAX.wrapping_sub(u256::from_u64(0x1_0000_03d0));
+        }
+
+        struct Bn254;
+        impl Modulus for Bn254 {
+            const MODULUS: u256 = u256::from_le_u64([
+                0x43e1f593f0000001,
+                0x2833e84879b97091,
+                0xb85045b68181585d,
+                0x30644e72e131a029,
+            ]);
+        }
+
+        struct Ed25519;
+        impl Modulus for Ed25519 {
+            const MODULUS: u256 = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        }
+
+        type F = Fp<Secp256k1>;
+        let three = F::from_u64(3);
+        let seven = F::from_u64(7);
+        assert_eq!(
+            (three / seven).to_string(),
+            "66166908135609254527754848576393090201868562666080322308261476575947905526665"
+        );
+        assert_eq!(three / seven * seven, three);
+        assert_eq!(three - seven + seven, three);
+        assert_eq!(-F::ONE, F::new(F::MODULUS.wrapping_sub(u256::from_u8(1))));
+        assert_eq!(F::new(u256::MAX), F::from_u64(0x1_0000_03d0));
+        assert_eq!(F::ZERO.inv(), None);
+        assert_eq!(seven.pow(F::MODULUS.wrapping_sub(u256::from_u8(1))), F::ONE);
+        assert_eq!(seven.square().sqrt().map(|r| r == seven || r == -seven), Some(true));
+        assert_eq!("7".parse::<F>().ok(), Some(seven));
+        assert!(F::MODULUS.to_string().parse::<F>().is_err());
+
+        type G = Fp<Bn254>;
+        let x = G::from_u64(0x1234_5678_9abc_def0);
+        assert_eq!(x.square().sqrt().map(|r| r == x || r == -x), Some(true));
+        assert_eq!(G::from_u64(5).sqrt(), None);
+        assert_eq!(G::ZERO.sqrt(), Some(G::ZERO));
+
+        type H = Fp<Ed25519>;
+        let x = H::from_u64(0x1234_5678_9abc_def0);
+        assert_eq!(x.square().sqrt().map(|r| r == x || r == -x), Some(true));
+        assert_eq!(H::from_u64(2).sqrt(), None);
+        assert_eq!(x / x, H::ONE);
+    }
+
+    #[test]
+    fn barrett_test() {
+        let ctx = Barrett::<u256>::new(u256::from_u8(100));
+        let (lo, hi) = u256::MAX.widening_mul(u256::MAX);
+        assert_eq!(ctx.modulus(), u256::from_u8(100));
+        assert_eq!(ctx.reduce_wide(lo, hi), u256::from_u8(25));
+        assert_eq!(ctx.mul(u256::from_u8(12), u256::from_u8(34)), u256::from_u8(8));
+        assert_eq!(Barrett::<u256>::checked_new(u256::MIN), None);
+
+        let one = Barrett::<u256>::new(u256::from_u8(1));
+        assert_eq!(one.reduce_wide(lo, hi), u256::MIN);
+        let two = Barrett::<u256>::new(u256::from_u8(2));
+        assert_eq!(two.reduce_wide(lo, hi), u256::from_u8(1));
+
+        let x = u256::from_le_u64([
+            0x0123_4567_89ab_cdef,
+            0xfedc_ba98_7654_3210,
+            0x0f1e_2d3c_4b5a_6978,
+            0x8796_a5b4_c3d2_e1f0,
+        ]);
+        let moduli = [
+            u256::from_u64(1 << 40),
+            u256::from_u128(u128::MAX),
+            u256::MAX.wrapping_shr(1).wrapping_add(u256::from_u8(1)),
+            u256::MAX.wrapping_sub(u256::from_u64(0x1_0000_03d0)),
+            u256::MAX,
+            x,
+        ];
+        for m in moduli {
+            let ctx = Barrett::<u256>::new(m);
+            assert_eq!(ctx.mul(x, u256::MAX), x.mul_mod(u256::MAX, m));
+            assert_eq!(ctx.reduce_wide(lo, hi), u256::wide_rem(lo, hi, m));
+            assert_eq!(ctx.reduce_wide(x, x), u256::wide_rem(x, x, m));
+        }
+
+        #[cfg(feature = "i512")]
+        {
+            let m = u256::from_u128(u128::MAX).wrapping_add(u256::from_u8(2));
+            let ctx = Barrett::<U256>::new(m);
+            let limbs = x.to_le_u64();
+            let wide = U512::from_le_u64([
+                limbs[0],
+                limbs[1],
+                limbs[2],
+                limbs[3],
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+                u64::MAX,
+            ]);
+            assert_eq!(ctx.reduce(wide), u256::wide_rem(x, u256::MAX, m));
+        }
+    }
+
+    #[test]
+    fn div_const_test() {
+        const TEN_18: u256 = u256::from_u64(10u64.pow(18));
+        const X: u256 = u256::MAX.div_const(TEN_18);
+        const Y: u256 = u256::MAX.rem_const(TEN_18);
+        assert_eq!((X, Y), u256::MAX.div_rem(TEN_18));
+
+        let values = [
+            u256::from_u8(1),
+            u256::from_u8(7),
+            u256::from_u64(u64::MAX),
+            u256::from_u128(u128::MAX),
+            u256::from_u128(u128::MAX).wrapping_mul(u256::from_u64(0x1234_5678)),
+            u256::MAX.wrapping_shr(1),
+            u256::MAX,
+        ];
+        for &x in values.iter() {
+            for &y in values.iter() {
+                assert_eq!(x.div_rem_const(y), x.div_rem(y));
+                assert_eq!(y.div_rem_const(x), y.div_rem(x));
+            }
+            assert_eq!(u256::from_u8(0).div_rem_const(x), (u256::from_u8(0), u256::from_u8(0)));
+        }
+    }
+
+    #[test]
+    fn divider_test() {
+        let numerators = [
+            u256::from_u8(0),
+            u256::from_u8(1),
+            u256::from_u64(u64::MAX),
+            u256::from_u128(u128::MAX).wrapping_mul(u256::from_u8(3)),
+            u256::MAX.wrapping_shr(1),
+            u256::MAX.wrapping_sub(u256::from_u8(1)),
+            u256::MAX,
+        ];
+        let divisors = [
+            u256::from_u8(1),
+            u256::from_u8(2),
+            u256::from_u8(3),
+            u256::from_u8(7),
+            u256::from_u8(10),
+            u256::from_u64(10u64.pow(18)),
+            u256::from_u64(0x12345678),
+            u256::from_u128(u128::MAX),
+            u256::from_u8(1).wrapping_shl(255),
+            u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18)),
+            u256::MAX.wrapping_sub(u256::from_u8(1)),
+            u256::MAX,
+        ];
+        for &d in divisors.iter() {
+            let divider = Divider::<u256>::new(d);
+            assert_eq!(divider.divisor(), d);
+            for &n in numerators.iter() {
+                assert_eq!(divider.div_rem(n), n.wrapping_div_rem(d));
+                assert_eq!(divider.div(n), n.wrapping_div(d));
+                assert_eq!(divider.rem(n), n.wrapping_rem(d));
+            }
+        }
+
+        for &d in [1, 3, 10, 0x12345678, ULimb::MAX - 1, ULimb::MAX].iter() {
+            let divider = Divider::<u256, ULimb>::new(d);
+            let wide = Divider::<u256, UWide>::new(d as UWide * 3);
+            for &n in numerators.iter() {
+                assert_eq!(divider.div_rem(n), n.wrapping_div_rem_ulimb(d));
+                assert_eq!(wide.div_rem(n), n.wrapping_div_rem_uwide(d as UWide * 3));
+            }
+        }
+
+        const DIV: Divider<u256> = Divider::<u256>::new(u256::from_u64(10u64.pow(18)));
+        assert_eq!(DIV.rem(u256::from_u64(10u64.pow(18) + 7)), u256::from_u8(7));
+        assert_eq!(Divider::<u256>::checked_new(u256::from_u8(0)), None);
+        assert_eq!(Divider::<u256, ULimb>::checked_new(0), None);
+    }
+
+    #[test]
+    fn div_round_test() {
+        use crate::rounding::reference::{div_round, MODES};
+
+        for mode in MODES {
+            for x in 0..40u8 {
+                for y in 1..9u8 {
+                    let expected = div_round(x as i64, y as i64, mode) as u8;
+                    let (xb, yb) = (u256::from_u8(x), u256::from_u8(y));
+                    let (quo, rem) = xb.div_rem_round(yb, mode);
+                    assert_eq!(quo, u256::from_u8(expected));
+                    assert_eq!(quo.wrapping_mul(yb).wrapping_add(rem), xb);
+                    assert_eq!(xb.div_round_ulimb(y as ULimb, mode), quo);
+                    assert_eq!(xb.round_to_multiple_of(yb, mode), quo * yb);
+                }
+            }
+        }
+
+        let half = u256::MAX.wrapping_shr(1);
+        assert_eq!(u256::MAX.div_round(u256::MAX, RoundingMode::Up), u256::from_u8(1));
+        assert_eq!(
+            u256::MAX.div_round(u256::from_u8(2), RoundingMode::Up),
+            half.wrapping_add_ulimb(1)
+        );
+        assert_eq!(
+            u256::MAX.div_round(u256::from_u8(2), RoundingMode::HalfEven),
+            half.wrapping_add_ulimb(1)
+        );
+        assert_eq!(
+            half.div_round(u256::from_u8(2), RoundingMode::HalfEven),
+            half.wrapping_shr(1).wrapping_add_ulimb(1)
+        );
+        assert_eq!(u256::MAX.round_to_multiple_of(u256::from_u8(3), RoundingMode::Up), u256::MAX);
+        assert_eq!(
+            u256::MAX.round_to_multiple_of(half, RoundingMode::HalfDown),
+            u256::MAX.wrapping_sub_ulimb(1)
+        );
+    }
+
+    #[test]
+    #[cfg(have_overflow_checks)]
+    #[should_panic]
+    fn round_to_multiple_of_overflow_test() {
+        _ = u256::MAX.round_to_multiple_of(u256::from_u8(2), RoundingMode::Up);
+    }
+
+    #[test]
+    fn mul_div_test() {
+        let x = u256::MAX.wrapping_sub(u256::from_u8(4));
+        assert_eq!(x.mul_div(x, x), x);
+        assert_eq!(u256::MAX.mul_div(u256::MAX, u256::MAX), u256::MAX);
+        assert_eq!(
+            u256::MAX.mul_div_rem(u256::from_u8(3), u256::from_u8(4)),
+            (
+                u256::MAX
+                    .wrapping_shr(2)
+                    .wrapping_mul(u256::from_u8(3))
+                    .wrapping_add(u256::from_u8(2)),
+                u256::from_u8(1)
+            )
+        );
+        assert_eq!(u256::from_u8(7).mul_div(u256::from_u8(3), u256::from_u8(2)), u256::from_u8(10));
+        assert_eq!(
+            u256::from_u8(7).mul_div_ceil(u256::from_u8(3), u256::from_u8(2)),
+            u256::from_u8(11)
+        );
+        assert_eq!(
+            u256::from_u8(8).mul_div_ceil(u256::from_u8(3), u256::from_u8(2)),
+            u256::from_u8(12)
+        );
+        assert_eq!(u256::MAX.checked_mul_div(u256::from_u8(2), u256::from_u8(1)), None);
+        assert_eq!(u256::MAX.checked_mul_div(u256::from_u8(2), u256::from_u8(0)), None);
+        assert_eq!(u256::MAX.checked_mul_div(u256::from_u8(2), u256::from_u8(2)), Some(u256::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mul_div_ceil_overflow_test() {
+        _ = u256::MAX.mul_div_ceil(
+            u256::MAX.wrapping_sub(u256::from_u8(1)),
+            u256::MAX.wrapping_sub(u256::from_u8(2)),
+        );
+    }
+
+    #[test]
+    fn gcd_test() {
+        let x = u256::from_u128(u128::MAX).wrapping_mul(u256::from_u8(6));
+        let y = u256::from_u128(u128::MAX).wrapping_mul(u256::from_u8(10));
+        assert_eq!(x.gcd(y), u256::from_u128(u128::MAX).wrapping_mul(u256::from_u8(2)));
+        assert_eq!(u256::MIN.gcd(y), y);
+        assert_eq!(y.gcd(u256::MIN), y);
+        assert_eq!(u256::MIN.gcd(u256::MIN), u256::MIN);
+        assert_eq!(u256::MAX.gcd(u256::MAX.wrapping_sub(u256::from_u8(1))), u256::from_u8(1));
+        assert_eq!(x.lcm(y), u256::from_u128(u128::MAX).wrapping_mul(u256::from_u8(30)));
+        assert_eq!(u256::MIN.lcm(y), u256::MIN);
+        assert_eq!(u256::MAX.checked_lcm(u256::from_u8(2)), None);
+
+        let (g, a, b) = u256::from_u8(240).extended_gcd(u256::from_u8(46));
+        assert_eq!((g, a, b), (u256::from_u8(2), i256::from_i8(-9), i256::from_i8(47)));
+        let (g, a, b) = u256::MAX.extended_gcd(u256::MAX.wrapping_sub(u256::from_u8(1)));
+        assert_eq!((g, a, b), (u256::from_u8(1), i256::from_u8(1), i256::from_i8(-1)));
+        assert_eq!(x.extended_gcd(u256::MIN), (x, i256::from_u8(1), i256::from_u8(0)));
+
+        let moduli = [u256::from_u8(3), u256::from_u8(5), u256::from_u8(7)];
+        let residues = [u256::from_u8(2), u256::from_u8(3), u256::from_u8(2)];
+        assert_eq!(u256::crt(&residues, &moduli), Some(u256::from_u8(23)));
+        let moduli = [u256::from_u8(6), u256::from_u8(10)];
+        assert_eq!(
+            u256::crt(&[u256::from_u8(5), u256::from_u8(3)], &moduli),
+            Some(u256::from_u8(23))
+        );
+        assert_eq!(u256::crt(&[u256::from_u8(1), u256::from_u8(2)], &moduli), None);
+        assert_eq!(u256::crt(&[], &[]), Some(u256::MIN));
+        assert_eq!(u256::crt(&[u256::from_u8(1)], &[u256::MIN]), None);
+        assert_eq!(u256::crt(&[u256::from_u8(1)], &[]), None);
+        assert_eq!(u256::crt(&[u256::MAX, u256::MAX], &[u256::MAX, u256::MAX]), Some(u256::MIN));
+        let moduli = [u256::MAX, u256::MAX.wrapping_sub(u256::from_u8(1))];
+        assert_eq!(u256::crt(&[u256::from_u8(1), u256::from_u8(1)], &moduli), None);
+    }
+
+    #[test]
+    fn combinatorics_test() {
+        assert_eq!(u256::checked_factorial(0), Some(u256::from_u8(1)));
+        assert_eq!(u256::checked_factorial(5), Some(u256::from_u8(120)));
+        assert_eq!(
+            u256::checked_factorial(34),
+            Some(u256::from_u128(0xde1bc4d19efcac82445da75b00000000))
+        );
+        assert!(u256::checked_factorial(57).is_some());
+        assert_eq!(u256::checked_factorial(58), None);
+
+        assert_eq!(u256::checked_falling_factorial(10, 0), Some(u256::from_u8(1)));
+        assert_eq!(u256::checked_falling_factorial(10, 3), Some(u256::from_u16(720)));
+        assert_eq!(u256::checked_falling_factorial(3, 4), Some(u256::from_u8(0)));
+        assert_eq!(
+            u256::checked_falling_factorial(60, 20),
+            Some(u256::from_u128(0x1f6d13c211ea1191a1ec456ac0000))
+        );
+        assert_eq!(u256::checked_falling_factorial(u32::MAX, 1), Some(u256::from_u32(u32::MAX)));
+
+        assert_eq!(u256::checked_binomial(0, 0), Some(u256::from_u8(1)));
+        assert_eq!(u256::checked_binomial(5, 2), Some(u256::from_u8(10)));
+        assert_eq!(u256::checked_binomial(5, 6), Some(u256::from_u8(0)));
+        assert_eq!(
+            u256::checked_binomial(100, 50),
+            Some(u256::from_u128(0x145ff5d3b1070380dc8085568))
+        );
+        assert_eq!(u256::checked_binomial(u32::MAX, u32::MAX - 1), Some(u256::from_u32(u32::MAX)));
+        // the largest central binomial coefficient that fits
+        let expected = u256::from_le_u64([
+            0x43389b59239142a4,
+            0x7f9db49cea9a0689,
+            0xdadae05593b8f1a1,
+            0xca7c813e1cb75343,
+        ]);
+        assert_eq!(u256::checked_binomial(260, 130), Some(expected));
+        assert_eq!(u256::checked_binomial(260, 129).map(|x| x < expected), Some(true));
+        assert_eq!(u256::checked_binomial(261, 130), None);
+    }
+
+    #[test]
+    fn prime_test() {
+        assert!(!u256::from_u8(0).is_prime());
+        assert!(!u256::from_u8(1).is_prime());
+        assert!(u256::from_u8(2).is_prime());
+        assert!(u256::from_u8(251).is_prime());
+        assert!(!u256::from_u32(257 * 257).is_prime());
+        // strong pseudoprime to base 2 and Lucas pseudoprime
+        assert!(!u256::from_u32(3215031751).is_prime());
+        assert!(!u256::from_u16(5459).is_prime());
+        // strong pseudoprime to the first 9 prime bases
+        assert!(!u256::from_u64(3825123056546413051).is_prime());
+        assert!(u256::from_u64(18446744073709551557).is_prime());
+
+        let p25519 = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        let p127 = u256::from_u128(u128::MAX >> 1);
+        assert!(p25519.is_prime());
+        assert!(p25519.is_probable_prime(20));
+        assert!(p127.is_prime());
+        assert!(!p127.wrapping_mul(p127).is_prime());
+        assert!(!p127.wrapping_mul(u256::from_u64(18446744073709551557)).is_probable_prime(20));
+        assert!(!u256::MAX.is_prime());
+
+        assert_eq!(u256::from_u8(0).next_prime(), Some(u256::from_u8(2)));
+        assert_eq!(u256::from_u8(2).next_prime(), Some(u256::from_u8(3)));
+        assert_eq!(u256::from_u8(7).next_prime(), Some(u256::from_u8(11)));
+        assert_eq!(u256::from_u8(2).prev_prime(), None);
+        assert_eq!(u256::from_u8(3).prev_prime(), Some(u256::from_u8(2)));
+        assert_eq!(u256::from_u8(12).prev_prime(), Some(u256::from_u8(11)));
+        assert_eq!(u256::MAX.prev_prime(), Some(u256::MAX.wrapping_sub(u256::from_u8(188))));
+        assert_eq!(u256::MAX.wrapping_sub(u256::from_u8(188)).next_prime(), None);
+        assert_eq!(p25519.wrapping_sub(u256::from_u8(1)).next_prime(), Some(p25519));
+    }
+
+    #[test]
+    fn factor_test() {
+        assert!(u256::from_u8(1).factor().is_empty());
+        assert_eq!(&*u256::from_u8(2).factor(), &[(u256::from_u8(2), 1)]);
+        assert_eq!(&*u256::from_u16(1024).factor(), &[(u256::from_u8(2), 10)]);
+        let expected = [(u256::from_u8(2), 3), (u256::from_u8(3), 2), (u256::from_u8(5), 1)];
+        assert_eq!(&*u256::from_u16(360).factor(), &expected);
+
+        let p31 = u256::from_u32((1 << 31) - 1);
+        let p127 = u256::from_u128(u128::MAX >> 1);
+        let p25519 = u256::MAX.wrapping_shr(1).wrapping_sub(u256::from_u8(18));
+        assert_eq!(&*p25519.factor(), &[(p25519, 1)]);
+        assert_eq!(&*p127.wrapping_mul(p127).factor(), &[(p127, 2)]);
+        let n = p31.wrapping_mul(p127).wrapping_mul(u256::from_u16(257 * 3));
+        let expected = [(u256::from_u8(3), 1), (u256::from_u16(257), 1), (p31, 1), (p127, 1)];
+        assert_eq!(&*n.factor(), &expected);
+
+        // 2^64 + 1 = 274177 * 67280421310721
+        let n = u256::from_u128((1 << 64) + 1);
+        let expected = [(u256::from_u32(274177), 1), (u256::from_u64(67280421310721), 1)];
+        assert_eq!(&*n.factor(), &expected);
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn num_traits_float_test() {
+        use num_traits::{FromPrimitive, ToPrimitive};
+
+        assert_eq!(<u256 as FromPrimitive>::from_f64(2.5), Some(u256::from_u8(2)));
+        assert_eq!(<u256 as FromPrimitive>::from_f32(-2.5), None);
+        assert_eq!(<u256 as ToPrimitive>::to_f64(&u256::from_u8(7)), Some(7.0));
+        assert_eq!(<i256 as ToPrimitive>::to_f32(&i256::from_i8(-7)), Some(-7.0));
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    fn convert_test() {
+        let x = u256::MAX.wrapping_sub(u256::from_u8(4));
+        let wide = U512::from(x);
+        assert_eq!(wide.to_le_limbs()[..u256::LIMBS], x.to_le_limbs());
+        assert_eq!(wide.wrapping_shr(256), U512::from_u8(0));
+        assert_eq!(I512::from(x), wide.as_signed());
+        assert_eq!(u256::try_from(wide), Ok(x));
+        assert_eq!(u256::try_from(wide.wrapping_add(U512::from(u256::MAX))), Err(TryFromIntError));
+        assert_eq!(u256::try_from(I512::from(x)), Ok(x));
+        assert_eq!(u256::try_from(I512::from_i8(-1)), Err(TryFromIntError));
+        assert_eq!(u256::wrapping_from_u512(U512::MAX), u256::MAX);
+        assert_eq!(u256::wrapping_from_i512(I512::from_i8(-5)), x);
+        assert_eq!(u256::saturating_from_u512(U512::MAX), u256::MAX);
+        assert_eq!(u256::saturating_from_i512(I512::MIN), u256::MIN);
+        assert_eq!(u256::saturating_from_i512(I512::MAX), u256::MAX);
+        assert_eq!(u256::checked_from_i512(I512::from(x)), Some(x));
+        assert_eq!(u256::checked_from_i512(I512::from_i8(-1)), None);
+        assert_eq!(U512::try_from(i256::from_i8(-1)), Err(TryFromIntError));
+        assert_eq!(U512::saturating_from_i256(i256::from_i8(-1)), U512::MIN);
+        assert_eq!(U512::wrapping_from_i256(i256::from_i8(-1)), U512::MAX);
+
+        const WIDE: U512 = U512::wrapping_from_u256(u256::MAX);
+        const NARROW: Option<u256> = u256::checked_from_u512(WIDE);
+        assert_eq!(NARROW, Some(u256::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    fn halves_test() {
+        let x = u256::MAX.wrapping_sub(u256::from_u8(4));
+        let y = u256::from_u128(u128::MAX).wrapping_add(u256::from_u8(7));
+        let wide = U512::from_halves(x, y);
+        assert_eq!(wide.split(), (x, y));
+        assert_eq!(wide, U512::from(x) | U512::from(y).wrapping_shl(256));
+
+        assert_eq!(x.widening_mul_into(y), U512::from(x).wrapping_mul(U512::from(y)));
+        assert_eq!(
+            u256::MAX.widening_mul_into(u256::MAX).split(),
+            u256::MAX.widening_mul(u256::MAX)
+        );
+        assert_eq!(x.widening_add(y), U512::from(x).wrapping_add(U512::from(y)));
+        assert_eq!(
+            u256::MAX.widening_add(u256::MAX),
+            U512::from_halves(u256::MAX.wrapping_sub(u256::from_u8(1)), u256::from_u8(1))
+        );
+        assert_eq!(x.widening_sub(y), I512::from(x).wrapping_sub(I512::from(y)));
+        assert_eq!(y.widening_sub(x), I512::from(y).wrapping_sub(I512::from(x)));
+        assert_eq!(u256::MIN.widening_sub(u256::MAX), I512::from(u256::MAX).wrapping_neg());
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    fn mixed_width_test() {
+        let x = U512::from_halves(u256::from_u8(3), u256::MAX.wrapping_sub(u256::from_u8(9)));
+        let y = u256::from_u128(u128::MAX).wrapping_add(u256::from_u8(7));
+        let wide_y = U512::from(y);
+
+        assert_eq!(x.wrapping_add_u256(y), x.wrapping_add(wide_y));
+        assert_eq!(x.overflowing_add_u256(y), x.overflowing_add(wide_y));
+        assert_eq!(U512::MAX.overflowing_add_u256(y), U512::MAX.overflowing_add(wide_y));
+        assert_eq!(x.wrapping_sub_u256(y), x.wrapping_sub(wide_y));
+        assert_eq!(U512::MIN.overflowing_sub_u256(y), U512::MIN.overflowing_sub(wide_y));
+        assert_eq!(x.wrapping_mul_u256(y), x.wrapping_mul(wide_y));
+        assert_eq!(x.overflowing_mul_u256(y), x.overflowing_mul(wide_y));
+        assert_eq!(U512::from(y).overflowing_mul_u256(y), (wide_y.wrapping_mul(wide_y), false));
+
+        let (div, rem) = x.div_rem_u256(y);
+        assert_eq!(div, x.wrapping_div(wide_y));
+        assert_eq!(U512::from(rem), x.wrapping_rem(wide_y));
+        assert_eq!(x.div_u256(y), div);
+        assert_eq!(x.rem_u256(y), rem);
+        assert_eq!(x.rem_u256(u256::MAX), x.wrapping_rem(U512::from(u256::MAX)).split().0);
+        assert_eq!(U512::from(y).div_rem_u256(x.split().1), (U512::MIN, y));
+
+        let product = u256::MAX.widening_mul_into(y);
+        assert_eq!(product.rem_u256(y), u256::MIN);
+        assert_eq!(product.div_u256(y), U512::from(u256::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    #[should_panic]
+    fn mixed_width_div_by_zero_test() {
+        _ = U512::MAX.rem_u256(u256::MIN);
+    }
+
+    #[test]
+    #[cfg(all(feature = "i512", feature = "num-traits"))]
+    fn num_traits_cast_test() {
+        use num_traits::NumCast;
+
+        // NOTE: Values wider than 128 bits can't be cast, even between our types.
+        let x = u256::MAX.wrapping_sub(u256::from_u8(4));
+        assert_eq!(<U512 as NumCast>::from(x), None);
+        assert_eq!(U512::checked_from_u256(x), Some(U512::wrapping_from_u256(x)));
+        assert_eq!(
+            <u256 as NumCast>::from(U512::from_u128(u128::MAX)),
+            Some(u256::from_u128(u128::MAX))
+        );
+        assert_eq!(<u256 as NumCast>::from(U512::MAX), None);
+        assert_eq!(<u256 as NumCast>::from(i256::from_i8(-1)), None);
+        assert_eq!(<u256 as NumCast>::from(u64::MAX), Some(u256::from_u64(u64::MAX)));
+    }
 }
 
 #[cfg(test)]
@@ -493,4 +1494,343 @@ mod i256_tests {
     fn product_test() {
         assert_eq!(i256::from_i8(-42), [i256::from_i8(6), i256::from_i8(-7)].into_iter().product());
     }
+
+    #[test]
+    fn float_test() {
+        assert_eq!(i256::from_i8(-3).to_f64(), -3.0);
+        assert_eq!(i256::MIN.to_f64(), -(2.0f64.powi(255)));
+        assert_eq!(i256::MAX.to_f64(), 2.0f64.powi(255));
+        assert_eq!(i256::MIN.to_f32(), f32::NEG_INFINITY);
+
+        assert_eq!(i256::checked_from_f64(-1.5), Some(i256::from_i8(-1)));
+        assert_eq!(i256::checked_from_f64(-(2.0f64.powi(255))), Some(i256::MIN));
+        assert_eq!(i256::checked_from_f64(2.0f64.powi(255)), None);
+        assert_eq!(i256::checked_from_f64(f64::INFINITY), None);
+        assert_eq!(i256::saturating_from_f64(f64::NEG_INFINITY), i256::MIN);
+        assert_eq!(i256::saturating_from_f64(2.0f64.powi(255)), i256::MAX);
+        assert_eq!(i256::saturating_from_f32(f32::NAN), i256::from_u8(0));
+        assert_eq!(i256::wrapping_from_f64(2.0f64.powi(255)), i256::MIN);
+        assert_eq!(i256::wrapping_from_f32(-7.9), i256::from_i8(-7));
+    }
+
+    #[test]
+    fn div_round_test() {
+        use crate::rounding::reference::{div_round, MODES};
+
+        for mode in MODES {
+            for x in -40..40i8 {
+                for y in -9..9i8 {
+                    if y == 0 {
+                        continue;
+                    }
+                    let expected = div_round(x as i64, y as i64, mode) as i8;
+                    let (xb, yb) = (i256::from_i8(x), i256::from_i8(y));
+                    let (quo, rem) = xb.div_rem_round(yb, mode);
+                    assert_eq!(quo, i256::from_i8(expected));
+                    assert_eq!(quo * yb + rem, xb);
+                    assert!(rem.unsigned_abs() < yb.unsigned_abs());
+                    assert_eq!(xb.div_round_ilimb(y as ILimb, mode), quo);
+
+                    let multiple = div_round(x as i64, (y as i64).abs(), mode) * (y as i64).abs();
+                    assert_eq!(xb.round_to_multiple_of(yb, mode), i256::from_i8(multiple as i8));
+                }
+            }
+        }
+
+        let half = i256::MIN.wrapping_shr(1);
+        assert_eq!(i256::MIN.div_round(i256::MIN, RoundingMode::Up), i256::from_u8(1));
+        assert_eq!(
+            i256::MIN.div_round(i256::from_i8(-2), RoundingMode::HalfEven),
+            i256::MAX.wrapping_shr(1).wrapping_add_ulimb(1)
+        );
+        assert_eq!(i256::MIN.div_round(i256::MAX, RoundingMode::Down), i256::from_i8(-2));
+        assert_eq!(i256::MIN.div_round(i256::MAX, RoundingMode::HalfEven), i256::from_i8(-1));
+        assert_eq!(i256::MIN.round_to_multiple_of(i256::from_i8(-1), RoundingMode::Up), i256::MIN);
+        assert_eq!(i256::MIN.round_to_multiple_of(half, RoundingMode::HalfEven), i256::MIN);
+        assert_eq!(
+            i256::MAX.round_to_multiple_of(i256::MIN, RoundingMode::TowardZero),
+            i256::from_u8(0)
+        );
+        assert_eq!(i256::MAX.round_to_multiple_of(i256::MIN, RoundingMode::Down), i256::from_u8(0));
+        assert_eq!(
+            i256::MIN.round_to_multiple_of(i256::MAX, RoundingMode::Up),
+            i256::MAX.wrapping_neg()
+        );
+    }
+
+    #[test]
+    #[cfg(have_overflow_checks)]
+    #[should_panic]
+    fn round_to_multiple_of_overflow_test() {
+        _ = i256::MAX.round_to_multiple_of(i256::from_i8(-2), RoundingMode::Up);
+    }
+
+    #[test]
+    fn mul_div_test() {
+        let x = i256::from_i8(-7);
+        assert_eq!(
+            x.mul_div_rem(i256::from_u8(3), i256::from_u8(2)),
+            (i256::from_i8(-10), i256::from_i8(-1))
+        );
+        assert_eq!(
+            x.mul_div_rem(i256::from_i8(-3), i256::from_u8(2)),
+            (i256::from_u8(10), i256::from_u8(1))
+        );
+        assert_eq!(
+            x.mul_div_rem(i256::from_u8(3), i256::from_i8(-2)),
+            (i256::from_u8(10), i256::from_i8(-1))
+        );
+        assert_eq!(x.mul_div_ceil(i256::from_u8(3), i256::from_u8(2)), i256::from_i8(-10));
+        assert_eq!(x.mul_div_ceil(i256::from_i8(-3), i256::from_u8(2)), i256::from_u8(11));
+        assert_eq!(i256::MAX.mul_div(i256::MAX, i256::MAX), i256::MAX);
+        assert_eq!(i256::MIN.mul_div(i256::MIN, i256::MIN), i256::MIN);
+        assert_eq!(i256::MIN.mul_div(i256::MAX, i256::MAX), i256::MIN);
+        assert_eq!(i256::MIN.checked_mul_div(i256::from_i8(-1), i256::from_u8(1)), None);
+        assert_eq!(
+            i256::MIN.checked_mul_div(i256::from_i8(-1), i256::from_i8(-1)),
+            Some(i256::MIN)
+        );
+        assert_eq!(i256::MIN.checked_mul_div(i256::from_u8(1), i256::from_u8(0)), None);
+    }
+
+    #[test]
+    fn gcd_test() {
+        assert_eq!(i256::from_i8(-12).gcd(i256::from_i8(18)), u256::from_u8(6));
+        assert_eq!(i256::MIN.gcd(i256::MIN), u256::from_u8(1).wrapping_shl(255));
+        assert_eq!(i256::MIN.gcd(i256::from_u8(0)), u256::from_u8(1).wrapping_shl(255));
+        assert_eq!(i256::from_i8(-4).lcm(i256::from_i8(-6)), u256::from_u8(12));
+        assert_eq!(
+            i256::MIN.checked_lcm(i256::from_u8(2)),
+            Some(u256::from_u8(1).wrapping_shl(255))
+        );
+        assert_eq!(i256::MIN.checked_lcm(i256::from_u8(3)), None);
+
+        let (g, x, y) = i256::from_i16(-240).extended_gcd(i256::from_i8(46));
+        assert_eq!((g, x, y), (u256::from_u8(2), i256::from_i8(9), i256::from_i8(47)));
+        let (g, x, y) = i256::MIN.extended_gcd(i256::MAX);
+        assert_eq!(g, u256::from_u8(1));
+        assert_eq!(
+            i256::MIN.wrapping_mul(x).wrapping_add(i256::MAX.wrapping_mul(y)),
+            i256::from_u8(1)
+        );
+    }
+
+    #[test]
+    fn square_test() {
+        let values = [
+            i256::from_i8(0),
+            i256::from_i8(-3),
+            i256::from_i128(i128::MIN),
+            i256::from_i128(i128::MAX),
+            i256::from_u128(u128::MAX),
+            -i256::from_u128(u128::MAX),
+            i256::MIN,
+            i256::MAX,
+        ];
+        for &x in values.iter() {
+            assert_eq!(x.wrapping_square(), x.wrapping_mul(x));
+            assert_eq!(x.overflowing_square(), x.overflowing_mul(x));
+            assert_eq!(x.checked_square(), x.checked_mul(x));
+        }
+        let x = -i256::from_u128(u128::MAX);
+        assert_eq!(
+            x.widening_square(),
+            (u256::from_u128(u128::MAX).wrapping_square(), i256::from_u8(0))
+        );
+        assert_eq!(
+            i256::MIN.widening_square(),
+            (u256::from_u8(0), i256::MAX.wrapping_shr(1) + i256::from_u8(1))
+        );
+        assert_eq!(i256::from_i8(-7).square(), i256::from_u8(49));
+        assert_eq!(i256::from_i8(-3).pow(159), -i256::from_u8(3).pow(159));
+        assert_eq!(i256::from_i8(-3).checked_pow(161), None);
+    }
+
+    #[test]
+    fn widening_mul_test() {
+        let values = [0i128, 1, -1, -3, i64::MIN as i128, u64::MAX as i128, i128::MIN, i128::MAX];
+        for &x in values.iter() {
+            for &y in values.iter() {
+                let product = i256::from_i128(x) * i256::from_i128(y);
+                let hi = i256::from_i8(-(product.is_negative() as i8));
+                assert_eq!(
+                    i256::from_i128(x).widening_mul(i256::from_i128(y)),
+                    (product.as_unsigned(), hi)
+                );
+                assert_eq!(
+                    i256::from_i128(x).carrying_mul(i256::from_i128(y), i256::from_i8(-1)),
+                    (
+                        (product - i256::from_u8(1)).as_unsigned(),
+                        i256::from_i8(-((product <= i256::from_u8(0)) as i8))
+                    )
+                );
+
+                let product = i256::from_i128(x) * i256::from_ilimb(y as ILimb);
+                let hi = -(product.is_negative() as ILimb);
+                assert_eq!(
+                    i256::from_i128(x).widening_mul_ilimb(y as ILimb),
+                    (product.as_unsigned(), hi)
+                );
+            }
+        }
+
+        for &x in [i256::MIN, i256::MAX, i256::from_i8(-1)].iter() {
+            assert_eq!(x.widening_mul(x), x.widening_square());
+        }
+        for &x in [i256::MAX, i256::from_i8(-1)].iter() {
+            let (lo, hi) = x.widening_mul(x.wrapping_neg());
+            let (neg_lo, neg_hi) = x.widening_square();
+            assert_eq!(
+                (lo, hi),
+                (
+                    neg_lo.wrapping_neg(),
+                    (!neg_hi).wrapping_add_ulimb(neg_lo.eq_const(u256::MIN) as ULimb)
+                )
+            );
+        }
+        assert_eq!(
+            i256::MIN.widening_mul(i256::MAX),
+            (u256::MIN.wrapping_sub(i256::MIN.as_unsigned()), i256::MIN.wrapping_shr(1))
+        );
+        assert_eq!(
+            i256::MIN.carrying_mul(i256::MIN, i256::MIN),
+            (i256::MIN.as_unsigned(), i256::MAX.wrapping_shr(1))
+        );
+        assert_eq!(
+            i256::MIN.carrying_mul(i256::MIN, i256::MAX),
+            (i256::MAX.as_unsigned(), i256::MAX.wrapping_shr(1) + i256::from_u8(1))
+        );
+        assert_eq!(
+            i256::MIN.widening_mul_ilimb(ILimb::MIN),
+            (u256::MIN, (1 as ILimb).wrapping_shl(ILimb::BITS - 2))
+        );
+        assert_eq!(
+            i256::MAX.widening_mul_ilimb(ILimb::MIN),
+            (u256::from_ulimb(ILimb::MIN as ULimb), -(1 as ILimb).wrapping_shl(ILimb::BITS - 2))
+        );
+
+        #[cfg(feature = "i512")]
+        for &(x, y) in [
+            (i256::MIN, i256::MAX),
+            (i256::MAX, i256::from_i8(-7)),
+            (i256::from_i128(i128::MIN), i256::MIN),
+        ]
+        .iter()
+        {
+            let (lo, hi) = x.widening_mul(y);
+            assert_eq!(I512::from_halves(lo, hi), I512::from(x) * I512::from(y));
+            let (lo, hi) = x.carrying_mul(y, i256::MIN);
+            assert_eq!(
+                I512::from_halves(lo, hi),
+                I512::from(x) * I512::from(y) + I512::from(i256::MIN)
+            );
+        }
+    }
+
+    #[test]
+    fn div_const_test() {
+        const X: i256 = i256::MIN.div_const(i256::from_i8(-7));
+        const Y: i256 = i256::MIN.rem_const(i256::from_i8(7));
+        assert_eq!(X, i256::MIN.wrapping_div(i256::from_i8(-7)));
+        assert_eq!(Y, i256::MIN.wrapping_rem(i256::from_i8(7)));
+
+        let values = [
+            i256::from_i8(-7),
+            i256::from_i8(3),
+            i256::from_i128(i128::MIN),
+            i256::from_u128(u128::MAX),
+            i256::MIN,
+            i256::MAX,
+        ];
+        for &x in values.iter() {
+            for &y in values.iter() {
+                assert_eq!(x.div_rem_const(y), x.wrapping_div_rem(y));
+            }
+        }
+    }
+
+    #[test]
+    fn jacobi_test() {
+        assert_eq!(i256::from_i8(-1).jacobi(i256::from_u8(5)), 1);
+        assert_eq!(i256::from_i8(-1).jacobi(i256::from_u8(7)), -1);
+        assert_eq!(i256::from_i8(-8).jacobi(i256::from_u8(21)), -1);
+        assert_eq!(i256::from_i8(-30).legendre(i256::from_u8(5)), 0);
+        assert_eq!(i256::MIN.jacobi(i256::from_u8(3)), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    fn convert_test() {
+        let x = i256::MIN.wrapping_add(i256::from_u8(4));
+        let wide = I512::from(x);
+        assert_eq!(wide, I512::from_i8(-1).wrapping_shl(255).wrapping_add(I512::from_u8(4)));
+        assert_eq!(I512::from(i256::from_i8(-1)), I512::from_i8(-1));
+        assert_eq!(
+            I512::from(u256::MAX),
+            I512::from_u8(1).wrapping_shl(256).wrapping_sub(I512::from_u8(1))
+        );
+        assert_eq!(i256::try_from(wide), Ok(x));
+        assert_eq!(i256::try_from(wide.wrapping_sub(I512::from_u8(5))), Err(TryFromIntError));
+        assert_eq!(
+            i256::try_from(I512::from(i256::MAX).wrapping_add(I512::from_u8(1))),
+            Err(TryFromIntError)
+        );
+        assert_eq!(i256::try_from(U512::from(i256::MAX.as_unsigned())), Ok(i256::MAX));
+        assert_eq!(i256::try_from(U512::from(u256::MAX)), Err(TryFromIntError));
+        assert_eq!(i256::wrapping_from_i512(I512::MIN), i256::from_u8(0));
+        assert_eq!(i256::wrapping_from_u512(U512::MAX), i256::from_i8(-1));
+        assert_eq!(i256::saturating_from_i512(I512::MIN), i256::MIN);
+        assert_eq!(i256::saturating_from_i512(I512::MAX), i256::MAX);
+        assert_eq!(i256::saturating_from_u512(U512::MAX), i256::MAX);
+        assert_eq!(i256::checked_from_i512(I512::from_i8(-7)), Some(i256::from_i8(-7)));
+        assert_eq!(I512::checked_from_u256(u256::MAX), Some(I512::from(u256::MAX)));
+        assert_eq!(I512::saturating_from_i256(i256::MIN), I512::from(i256::MIN));
+    }
+
+    #[test]
+    #[cfg(feature = "i512")]
+    fn halves_test() {
+        let lo = u256::MAX.wrapping_sub(u256::from_u8(4));
+        let wide = I512::from_halves(lo, i256::from_i8(-1));
+        assert_eq!(wide, I512::from(lo.as_signed()));
+        assert_eq!(wide.split(), (lo, i256::from_i8(-1)));
+        assert_eq!(I512::MIN.split(), (u256::MIN, i256::MIN));
+
+        let x = i256::MIN.wrapping_add(i256::from_u8(3));
+        let y = i256::from_i8(-7);
+        assert_eq!(x.widening_mul_into(y), I512::from(x).wrapping_mul(I512::from(y)));
+        assert_eq!(
+            x.widening_mul_into(y.wrapping_neg()),
+            I512::from(x).wrapping_mul(I512::from(y.wrapping_neg()))
+        );
+        assert_eq!(i256::MIN.widening_mul_into(i256::MIN), I512::from_u8(1).wrapping_shl(510));
+        assert_eq!(
+            i256::MIN.widening_mul_into(i256::MAX),
+            I512::from(i256::MIN).wrapping_mul(I512::from(i256::MAX))
+        );
+        assert_eq!(i256::MIN.widening_add(i256::MIN), I512::from(i256::MIN).wrapping_shl(1));
+        assert_eq!(i256::MAX.widening_add(i256::MAX), I512::from(i256::MAX).wrapping_shl(1));
+        assert_eq!(
+            i256::MIN.widening_sub(i256::MAX),
+            I512::from(i256::MIN).wrapping_sub(I512::from(i256::MAX))
+        );
+        assert_eq!(x.widening_sub(y), I512::from(x).wrapping_sub(I512::from(y)));
+    }
+
+    #[test]
+    #[cfg(all(feature = "i512", feature = "num-traits"))]
+    fn num_traits_cast_test() {
+        use num_traits::NumCast;
+
+        // NOTE: Values wider than 128 bits can't be cast, even between our types.
+        assert_eq!(<I512 as NumCast>::from(i256::MIN), None);
+        assert_eq!(I512::checked_from_i256(i256::MIN), Some(I512::wrapping_from_i256(i256::MIN)));
+        assert_eq!(
+            <i256 as NumCast>::from(I512::from_i128(i128::MIN)),
+            Some(i256::from_i128(i128::MIN))
+        );
+        assert_eq!(<i256 as NumCast>::from(I512::MIN), None);
+        assert_eq!(<U512 as NumCast>::from(i256::from_i8(-1)), None);
+        assert_eq!(<i256 as NumCast>::from(-5i64), Some(i256::from_i8(-5)));
+    }
 }
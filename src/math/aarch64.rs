@@ -0,0 +1,259 @@
+//! Addition and multiplication kernels for `aarch64`.
+//!
+//! `aarch64` has no instruction for a full 128-bit product, so each
+//! limb product requires a `MUL` and `UMULH`. These kernels keep the
+//! carries in the flags using `ADDS`/`ADCS` chains, and since `MUL`
+//! and `UMULH` don't modify the flags, the limb products can be
+//! scheduled freely between them.
+//!
+//! Each row of a long multiplication needs 2 carry chains, one for
+//! the low halves of the limb products and one for the high halves,
+//! but `aarch64` only has a single carry flag. So, each row adds the
+//! low halves to the accumulator with one chain, and then the high
+//! halves, shifted by 1 limb, with another.
+//!
+//! These are only defined for 256-bit operands, that is, 4 limbs,
+//! and are fully unrolled, so all limbs stay in registers. The
+//! non-`const` additions in [`add`][super::add] and multiplications
+//! in [`mul`][super::mul] use them for 4-limb operands.
+
+#![doc(hidden)]
+
+use core::arch::asm;
+
+// NOTE: `asm!` templates can't depend on const generics, and looping over
+// limbs requires a branch and memory round-trips for each limb product, so
+// other sizes always use the portable algorithms. The limbs are in order,
+// since the module is only compiled for little-endian targets, so we don't
+// need to use `ne_index!`.
+
+/// Overflowing addition of 4 limbs, keeping the carries in the flags.
+///
+/// This has the same semantics as
+/// [`overflowing_u64`][super::add::overflowing_u64].
+#[inline(always)]
+pub fn overflowing_add(x: &[u64; 4], y: &[u64; 4]) -> ([u64; 4], bool) {
+    let (r0, r1, r2, r3, carry): (u64, u64, u64, u64, u64);
+    // SAFETY: Safe, since this only uses registers and the base instruction
+    // set.
+    unsafe {
+        asm!(
+            "adds {a0}, {x0}, {y0}",
+            "adcs {a1}, {x1}, {y1}",
+            "adcs {a2}, {x2}, {y2}",
+            "adcs {a3}, {x3}, {y3}",
+            "cset {c}, cs",
+            x0 = in(reg) x[0],
+            x1 = in(reg) x[1],
+            x2 = in(reg) x[2],
+            x3 = in(reg) x[3],
+            y0 = in(reg) y[0],
+            y1 = in(reg) y[1],
+            y2 = in(reg) y[2],
+            y3 = in(reg) y[3],
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            c = out(reg) carry,
+            options(pure, nomem, nostack),
+        );
+    }
+    ([r0, r1, r2, r3], carry != 0)
+}
+
+/// Wrapping addition of 4 limbs, keeping the carries in the flags.
+///
+/// This has the same semantics as [`wrapping_u64`][super::add::wrapping_u64].
+#[inline(always)]
+pub fn wrapping_add(x: &[u64; 4], y: &[u64; 4]) -> [u64; 4] {
+    overflowing_add(x, y).0
+}
+
+/// Wrapping multiplication of 4 limbs, keeping the carries in the flags.
+///
+/// This has the same semantics as [`wrapping_u64`][super::mul::wrapping_u64].
+#[inline(always)]
+pub fn wrapping_mul(x: &[u64; 4], y: &[u64; 4]) -> [u64; 4] {
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // NOTE: Only the limb products which contribute to the low 4 limbs are
+    // calculated, so each row is 1 limb shorter than the previous one, and
+    // the last addition of each row can't carry into anything we keep.
+    // SAFETY: Safe, since this only uses registers and the base instruction
+    // set.
+    unsafe {
+        asm!(
+            "mul {a0}, {x0}, {y0}",
+            "umulh {a1}, {x0}, {y0}",
+            "mul {t0}, {x0}, {y1}",
+            "umulh {a2}, {x0}, {y1}",
+            "mul {t1}, {x0}, {y2}",
+            "umulh {a3}, {x0}, {y2}",
+            "mul {t2}, {x0}, {y3}",
+            "adds {a1}, {a1}, {t0}",
+            "adcs {a2}, {a2}, {t1}",
+            "adc {a3}, {a3}, {t2}",
+            "mul {t0}, {x1}, {y0}",
+            "mul {t1}, {x1}, {y1}",
+            "mul {t2}, {x1}, {y2}",
+            "umulh {t3}, {x1}, {y0}",
+            "adds {a1}, {a1}, {t0}",
+            "adcs {a2}, {a2}, {t1}",
+            "adc {a3}, {a3}, {t2}",
+            "umulh {t0}, {x1}, {y1}",
+            "adds {a2}, {a2}, {t3}",
+            "adc {a3}, {a3}, {t0}",
+            "mul {t0}, {x2}, {y0}",
+            "mul {t1}, {x2}, {y1}",
+            "umulh {t2}, {x2}, {y0}",
+            "mul {t3}, {x3}, {y0}",
+            "adds {a2}, {a2}, {t0}",
+            "adc {a3}, {a3}, {t1}",
+            "add {a3}, {a3}, {t2}",
+            "add {a3}, {a3}, {t3}",
+            x0 = in(reg) x[0],
+            x1 = in(reg) x[1],
+            x2 = in(reg) x[2],
+            x3 = in(reg) x[3],
+            y0 = in(reg) y[0],
+            y1 = in(reg) y[1],
+            y2 = in(reg) y[2],
+            y3 = in(reg) y[3],
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            t3 = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// Widening multiplication of 4 limbs, which returns both the low and high
+/// bits, keeping the carries in the flags.
+///
+/// This has the same semantics as
+/// [`widening_long_u64`][super::mul::widening_long_u64].
+#[inline(always)]
+pub fn widening_mul(x: &[u64; 4], y: &[u64; 4]) -> ([u64; 4], [u64; 4]) {
+    let (r0, r1, r2, r3, r4, r5, r6, r7): (u64, u64, u64, u64, u64, u64, u64, u64);
+    // NOTE: The first row is a single carry chain, since there is nothing to
+    // add the low halves to. Every other row adds the low halves and then
+    // the high halves, and the final carry of each row can't overflow, since
+    // the product of 2 limbs plus 2 limbs always fits in 2 limbs.
+    // SAFETY: Safe, since this only uses registers and the base instruction
+    // set.
+    unsafe {
+        asm!(
+            "mul {a0}, {x0}, {y0}",
+            "umulh {t0}, {x0}, {y0}",
+            "mul {a1}, {x0}, {y1}",
+            "umulh {t1}, {x0}, {y1}",
+            "mul {a2}, {x0}, {y2}",
+            "umulh {t2}, {x0}, {y2}",
+            "mul {a3}, {x0}, {y3}",
+            "umulh {a4}, {x0}, {y3}",
+            "adds {a1}, {a1}, {t0}",
+            "adcs {a2}, {a2}, {t1}",
+            "adcs {a3}, {a3}, {t2}",
+            "adc {a4}, {a4}, xzr",
+            "mul {t0}, {x1}, {y0}",
+            "mul {t1}, {x1}, {y1}",
+            "mul {t2}, {x1}, {y2}",
+            "mul {t3}, {x1}, {y3}",
+            "adds {a1}, {a1}, {t0}",
+            "adcs {a2}, {a2}, {t1}",
+            "adcs {a3}, {a3}, {t2}",
+            "adcs {a4}, {a4}, {t3}",
+            "cset {a5}, cs",
+            "umulh {t0}, {x1}, {y0}",
+            "umulh {t1}, {x1}, {y1}",
+            "umulh {t2}, {x1}, {y2}",
+            "umulh {t3}, {x1}, {y3}",
+            "adds {a2}, {a2}, {t0}",
+            "adcs {a3}, {a3}, {t1}",
+            "adcs {a4}, {a4}, {t2}",
+            "adc {a5}, {a5}, {t3}",
+            "mul {t0}, {x2}, {y0}",
+            "mul {t1}, {x2}, {y1}",
+            "mul {t2}, {x2}, {y2}",
+            "mul {t3}, {x2}, {y3}",
+            "adds {a2}, {a2}, {t0}",
+            "adcs {a3}, {a3}, {t1}",
+            "adcs {a4}, {a4}, {t2}",
+            "adcs {a5}, {a5}, {t3}",
+            "cset {a6}, cs",
+            "umulh {t0}, {x2}, {y0}",
+            "umulh {t1}, {x2}, {y1}",
+            "umulh {t2}, {x2}, {y2}",
+            "umulh {t3}, {x2}, {y3}",
+            "adds {a3}, {a3}, {t0}",
+            "adcs {a4}, {a4}, {t1}",
+            "adcs {a5}, {a5}, {t2}",
+            "adc {a6}, {a6}, {t3}",
+            "mul {t0}, {x3}, {y0}",
+            "mul {t1}, {x3}, {y1}",
+            "mul {t2}, {x3}, {y2}",
+            "mul {t3}, {x3}, {y3}",
+            "adds {a3}, {a3}, {t0}",
+            "adcs {a4}, {a4}, {t1}",
+            "adcs {a5}, {a5}, {t2}",
+            "adcs {a6}, {a6}, {t3}",
+            "cset {a7}, cs",
+            "umulh {t0}, {x3}, {y0}",
+            "umulh {t1}, {x3}, {y1}",
+            "umulh {t2}, {x3}, {y2}",
+            "umulh {t3}, {x3}, {y3}",
+            "adds {a4}, {a4}, {t0}",
+            "adcs {a5}, {a5}, {t1}",
+            "adcs {a6}, {a6}, {t2}",
+            "adc {a7}, {a7}, {t3}",
+            x0 = in(reg) x[0],
+            x1 = in(reg) x[1],
+            x2 = in(reg) x[2],
+            x3 = in(reg) x[3],
+            y0 = in(reg) y[0],
+            y1 = in(reg) y[1],
+            y2 = in(reg) y[2],
+            y3 = in(reg) y[3],
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            a4 = out(reg) r4,
+            a5 = out(reg) r5,
+            a6 = out(reg) r6,
+            a7 = out(reg) r7,
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            t3 = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    ([r0, r1, r2, r3], [r4, r5, r6, r7])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::reference::fill;
+    use crate::math::{add, mul};
+
+    #[test]
+    fn differential_test() {
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        for i in 0..20000 {
+            let x = fill(&mut state, i);
+            let y = fill(&mut state, i / 4);
+            assert_eq!(wrapping_add(&x, &y), add::wrapping_u64(&x, &y));
+            assert_eq!(overflowing_add(&x, &y), add::overflowing_u64(&x, &y));
+            assert_eq!(wrapping_mul(&x, &y), mul::wrapping_u64(&x, &y));
+            assert_eq!(widening_mul(&x, &y), mul::widening_long_u64(&x, &y));
+        }
+    }
+}
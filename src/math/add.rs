@@ -319,6 +319,49 @@ unsigned_define!(
 limb_function!(wrapping_unsigned, wrapping_u64, wrapping_u32, &[ULimb; N], ret => [ULimb; N]);
 limb_function!(overflowing_unsigned, overflowing_u64, overflowing_u32, &[ULimb; N], &[ULimb; N], ret => ([ULimb; N], bool));
 
+/// Non-`const` version of [`wrapping_unsigned`].
+///
+/// On little-endian `aarch64`, 4-limb operands use `aarch64::wrapping_add`.
+#[inline(always)]
+pub fn wrapping_runtime<const N: usize>(x: &[ULimb; N], y: &[ULimb; N]) -> [ULimb; N] {
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_endian = "little",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        let r = super::aarch64::wrapping_add(x4, y4);
+        if let Ok(r) = <[ULimb; N]>::try_from(&r[..]) {
+            return r;
+        }
+    }
+
+    wrapping_unsigned(x, y)
+}
+
+/// Non-`const` version of [`overflowing_unsigned`].
+///
+/// On little-endian `aarch64`, 4-limb operands use
+/// `aarch64::overflowing_add`.
+#[inline(always)]
+pub fn overflowing_runtime<const N: usize>(x: &[ULimb; N], y: &[ULimb; N]) -> ([ULimb; N], bool) {
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_endian = "little",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        let (r, overflowed) = super::aarch64::overflowing_add(x4, y4);
+        if let Ok(r) = <[ULimb; N]>::try_from(&r[..]) {
+            return (r, overflowed);
+        }
+    }
+
+    overflowing_unsigned(x, y)
+}
+
 // limb
 limb_function!(wrapping_limb, wrapping_limb_u64, wrapping_limb_u32, &[ULimb; N], ULimb, ret => [ULimb; N]);
 limb_function!(overflowing_limb, overflowing_limb_u64, overflowing_limb_u32, &[ULimb; N], ULimb, ret => ([ULimb; N], bool));
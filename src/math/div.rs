@@ -33,7 +33,8 @@ use crate::types::{ULimb, UWide};
 // for a few reasons, so we prioritize this. It used extensive unchecked
 // indexing and the array sizes were not known at compile time, so it was a
 // refactor away from potential memory unsafety. It also assumed big endian
-// order, and on most systems our data is in little endian order.
+// order, and on most systems our data is in little endian order. For `const`
+// contexts, use [`full_const`], which is a separate, much slower algorithm.
 
 /// Unsigned, little-endian, n-digit division with remainder.
 ///
@@ -63,6 +64,60 @@ pub fn full<const M: usize, const N: usize>(
     }
 }
 
+/// Unsigned, little-endian, n-digit division with remainder, which can be
+/// used in `const` contexts.
+///
+/// This uses the hardware division for each digit for single-digit divisors,
+/// and otherwise binary long division, which requires a comparison and
+/// subtraction for each bit of the quotient. This is much slower than
+/// [`full`], so it should only be used for constants.
+///
+/// # Panics
+///
+/// Panics if divisor is zero.
+#[inline]
+#[must_use]
+pub const fn full_const<const N: usize>(
+    numerator: &[ULimb; N],
+    divisor: &[ULimb; N],
+) -> ([ULimb; N], [ULimb; N]) {
+    assert!(!is_zero(divisor, 0), "attempt to divide by zero");
+    if matches!(cmp(numerator, divisor), Ordering::Less) {
+        return ([0; N], *numerator);
+    }
+
+    let mut quotient = [0; N];
+    if last_index(divisor) == 0 {
+        let d = divisor[0] as UWide;
+        let mut r = 0;
+        let mut i = last_index(numerator) + 1;
+        while i > 0 {
+            i -= 1;
+            let u = (r << ULimb::BITS) | numerator[i] as UWide;
+            quotient[i] = (u / d) as ULimb;
+            r = u % d;
+        }
+        return (quotient, scalar1(r as ULimb));
+    }
+
+    // NOTE: This aligns the most-significant bit of the divisor with the
+    // numerator, and then subtracts the shifted divisor whenever it fits.
+    let shift = bit_length(numerator) - bit_length(divisor);
+    let mut shifted = shl_bits(divisor, shift);
+    let mut rem = *numerator;
+    let mut i = shift + 1;
+    while i > 0 {
+        i -= 1;
+        if !matches!(cmp(&rem, &shifted), Ordering::Less) {
+            rem = wrapping_sub(&rem, &shifted);
+            quotient[(i / ULimb::BITS) as usize] |= 1 << (i % ULimb::BITS);
+        }
+        shifted = shr_word(&shifted, 1);
+    }
+
+    (quotient, rem)
+}
+
 /// Division of a numerator by a u128 divisor.
 ///
 /// Performance of this is highly variable: for small
@@ -142,18 +197,69 @@ pub fn limb<const M: usize>(numerator: &[ULimb; M], divisor: ULimb) -> ([ULimb;
         return (scalar2(quo), rem as ULimb);
     }
 
-    let mut numerator = *numerator;
-    let mut r = 0;
-    let mut i = M;
+    // NOTE: On x86_64, `div_rem_word` is a single `div` instruction, which
+    // benchmarks ~25% faster than the reciprocal for `div_rem_ulimb` and
+    // `to_string`, since the reciprocal itself requires a division and
+    // the quotient needs 2 multiplications and a correction per limb.
+    // Elsewhere, a 2-by-1 division is usually a call to `__udivti3`, which
+    // is much slower.
+    #[cfg(all(target_arch = "x86_64", not(feature = "noasm"), not(feature = "limb32")))]
+    {
+        let mut numerator = *numerator;
+        let mut r = 0;
+        let mut i = last_index(&numerator) + 1;
+        while i > 0 {
+            i -= 1;
+            let d = numerator[i];
+            let (q, ri) = div_rem_word(r, d, divisor);
+            numerator[i] = q;
+            r = ri;
+        }
+
+        (numerator, r)
+    }
+
+    #[cfg(any(not(target_arch = "x86_64"), feature = "noasm", feature = "limb32"))]
+    {
+        limb_reciprocal(numerator, divisor)
+    }
+}
+
+/// Division of numerator by a u64 divisor, using the Möller-Granlund 2-by-1
+/// division with a precomputed reciprocal.
+#[inline]
+#[cfg_attr(
+    all(target_arch = "x86_64", not(feature = "noasm"), not(feature = "limb32")),
+    allow(dead_code)
+)]
+fn limb_reciprocal<const M: usize>(numerator: &[ULimb; M], divisor: ULimb) -> ([ULimb; M], ULimb) {
+    // NOTE: We normalize the divisor so its most-significant bit is set,
+    // which is required for the reciprocal, and shift the numerator by the
+    // same amount as we go. This doesn't change the quotient, and the
+    // remainder is shifted back at the end. Any leading zero digits have
+    // a zero quotient, which is common for repeated divisions, such as
+    // when writing the value to a string.
+    let shift = divisor.leading_zeros();
+    let divisor = divisor << shift;
+    let v = reciprocal_word(divisor);
+    let mut quotient = [0; M];
+    let mut i = last_index(numerator) + 1;
+    let mut r = match shift {
+        0 => 0,
+        _ => numerator[i - 1] >> (ULimb::BITS - shift),
+    };
     while i > 0 {
         i -= 1;
-        let d = numerator[i];
-        let (q, ri) = div_rem_word(r, d, divisor);
-        numerator[i] = q;
+        let mut d = numerator[i] << shift;
+        if shift != 0 && i > 0 {
+            d |= numerator[i - 1] >> (ULimb::BITS - shift);
+        }
+        let (q, ri) = div_rem_2by1(r, d, divisor, v);
+        quotient[i] = q;
         r = ri;
     }
 
-    (numerator, r)
+    (quotient, r >> shift)
 }
 
 /// Internal variant that assumes our numerator >= divisor,
@@ -229,6 +335,62 @@ const fn last_index<const N: usize>(x: &[ULimb; N]) -> usize {
     0
 }
 
+/// Get the number of significant bits in the array.
+#[inline(always)]
+const fn bit_length<const N: usize>(x: &[ULimb; N]) -> u32 {
+    let index = last_index(x);
+    (index as u32 + 1) * ULimb::BITS - x[index].leading_zeros()
+}
+
+/// Left shift of an N-digit array by any number of bits, truncating the result.
+#[inline(always)]
+const fn shl_bits<const N: usize>(x: &[ULimb; N], shift: u32) -> [ULimb; N] {
+    let words = (shift / ULimb::BITS) as usize;
+    let bits = shift % ULimb::BITS;
+    let mut out = [0; N];
+    let mut i = N;
+    while i > words {
+        i -= 1;
+        out[i] = x[i - words] << bits;
+        if bits != 0 && i > words {
+            out[i] |= x[i - words - 1] >> (ULimb::BITS - bits);
+        }
+    }
+    out
+}
+
+/// Right shift of an N-digit array by less than the number of bits in a digit.
+#[inline(always)]
+const fn shr_word<const N: usize>(x: &[ULimb; N], shift: u32) -> [ULimb; N] {
+    debug_assert!(shift > 0 && shift < ULimb::BITS);
+    let mut out = [0; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = x[i] >> shift;
+        if i + 1 < N {
+            out[i] |= x[i + 1] << (ULimb::BITS - shift);
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Wrapping subtraction of 2 N-digit arrays.
+#[inline(always)]
+const fn wrapping_sub<const N: usize>(x: &[ULimb; N], y: &[ULimb; N]) -> [ULimb; N] {
+    let mut out = [0; N];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < N {
+        let (v, b0) = x[i].overflowing_sub(y[i]);
+        let (v, b1) = v.overflowing_sub(borrow as ULimb);
+        out[i] = v;
+        borrow = b0 || b1;
+        i += 1;
+    }
+    out
+}
+
 /// Construct an array from a limb.
 #[inline(always)]
 pub const fn scalar1<const N: usize>(value: ULimb) -> [ULimb; N] {
@@ -332,9 +494,10 @@ fn div_rem_knuth<const M: usize, const N: usize>(
     // digit
     let mut numerator = full_shl(numerator, shift);
 
-    // The two most significant digits of the divisor
+    // The two most significant digits of the divisor, and their reciprocal
     let b0 = divisor[n - 1];
     let b1 = divisor[n - 2];
+    let v = reciprocal_3by2(b0, b1);
 
     let mut q = [0; M];
 
@@ -344,35 +507,11 @@ fn div_rem_knuth<const M: usize, const N: usize>(
         let a0 = numerator.get(j + n);
         let a1 = numerator.0[j + n - 1];
 
-        let mut q_hat = if a0 < b0 {
-            // The first estimate is [a1, a0] / b0, it may be too large by at most 2
-            let (mut q_hat, mut r_hat) = div_rem_word(a0, a1, b0);
-
-            // r_hat = [a1, a0] - q_hat * b0
-            //
-            // Now we want to compute a more precise estimate [a2,a1,a0] / [b1,b0]
-            // which can only be less or equal to the current q_hat
-            //
-            // q_hat is too large if:
-            // [a2,a1,a0] < q_hat * [b1,b0]
-            // [a2,r_hat] < q_hat * b1
+        let mut q_hat = if (a0, a1) < (b0, b1) {
+            // The estimate is [a2,a1,a0] / [b1,b0], which is the exact quotient
+            // of the top 3 digits, and may only be too large by 1
             let a2 = numerator.0[j + n - 2];
-            let r = (q_hat as UWide) * (b1 as UWide);
-            let (lo, hi) = (r as ULimb, (r >> ULimb::BITS) as ULimb);
-            if (hi, lo) > (r_hat, a2) {
-                q_hat -= 1;
-                let (new_r_hat, overflow) = r_hat.overflowing_add(b0);
-                r_hat = new_r_hat;
-
-                if !overflow {
-                    let r = (q_hat as UWide) * (b1 as UWide);
-                    let (lo, hi) = (r as ULimb, (r >> ULimb::BITS) as ULimb);
-                    if (hi, lo) > (r_hat, a2) {
-                        q_hat -= 1;
-                    }
-                }
-            }
-            q_hat
+            div_rem_3by2(a0, a1, a2, b0, b1, v).0
         } else {
             ULimb::MAX
         };
@@ -401,7 +540,7 @@ fn div_rem_knuth<const M: usize, const N: usize>(
             // Add back one multiple of divisor
             i = 0;
             c = false;
-            while i < n + 1 {
+            while i < n {
                 let x = numerator.get(i + j);
                 let y = divisor[i];
                 let (res1, overflow1) = y.overflowing_add(c as ULimb);
@@ -505,6 +644,114 @@ fn div_rem_word(hi: ULimb, lo: ULimb, divisor: ULimb) -> (ULimb, ULimb) {
     }
 }
 
+/// Widening multiplication of 2 digits, returning the low and high digits.
+#[inline(always)]
+const fn mul_word(x: ULimb, y: ULimb) -> (ULimb, ULimb) {
+    let r = x as UWide * y as UWide;
+    (r as ULimb, (r >> ULimb::BITS) as ULimb)
+}
+
+/// Calculate the reciprocal `floor((B^2 - 1) / d) - B` of a normalized digit,
+/// where `B = 2^BITS`, for the Möller-Granlund 2-by-1 division.
+///
+/// This requires a single hardware division, so it should be calculated once
+/// for each divisor.
+#[inline(always)]
+fn reciprocal_word(d: ULimb) -> ULimb {
+    debug_assert!(d.leading_zeros() == 0);
+    // NOTE: `B^2 - 1 - B * d == (B - 1 - d) * B + (B - 1)`.
+    div_rem_word(!d, ULimb::MAX, d).0
+}
+
+/// Calculate the reciprocal `floor((B^3 - 1) / [d0,d1]) - B` of a normalized
+/// 2-digit divisor, where `d1` is the most significant digit, for the
+/// Möller-Granlund 3-by-2 division.
+///
+/// See "Improved division by invariant integers", Algorithm 6.
+#[inline(always)]
+fn reciprocal_3by2(d1: ULimb, d0: ULimb) -> ULimb {
+    let mut v = reciprocal_word(d1);
+    let mut p = d1.wrapping_mul(v).wrapping_add(d0);
+    if p < d0 {
+        v = v.wrapping_sub(1);
+        if p >= d1 {
+            v = v.wrapping_sub(1);
+            p = p.wrapping_sub(d1);
+        }
+        p = p.wrapping_sub(d1);
+    }
+    let (t0, t1) = mul_word(v, d0);
+    p = p.wrapping_add(t1);
+    if p < t1 {
+        v = v.wrapping_sub(1);
+        if (p, t0) >= (d1, d0) {
+            v = v.wrapping_sub(1);
+        }
+    }
+    v
+}
+
+/// Divide `[u0,u1]` by the normalized digit `d`, where `u1` is the most
+/// significant digit and `u1 < d`, using its reciprocal `v`.
+///
+/// This replaces the hardware division with 2 multiplications and at most 2
+/// corrections. See "Improved division by invariant integers", Algorithm 4.
+#[inline(always)]
+#[cfg_attr(
+    all(target_arch = "x86_64", not(feature = "noasm"), not(feature = "limb32")),
+    allow(dead_code)
+)]
+const fn div_rem_2by1(u1: ULimb, u0: ULimb, d: ULimb, v: ULimb) -> (ULimb, ULimb) {
+    debug_assert!(u1 < d);
+    let (q0, q1) = mul_word(v, u1);
+    let (q0, carry) = q0.overflowing_add(u0);
+    let mut q1 = q1.wrapping_add(u1).wrapping_add(carry as ULimb).wrapping_add(1);
+    let mut r = u0.wrapping_sub(q1.wrapping_mul(d));
+    if r > q0 {
+        q1 = q1.wrapping_sub(1);
+        r = r.wrapping_add(d);
+    }
+    if r >= d {
+        q1 = q1.wrapping_add(1);
+        r = r.wrapping_sub(d);
+    }
+    (q1, r)
+}
+
+/// Divide `[u0,u1,u2]` by the normalized divisor `[d0,d1]`, where `u2` and `d1`
+/// are the most significant digits and `[u1,u2] < [d0,d1]`, using its 3-by-2
+/// reciprocal `v`. This returns the quotient and the remainder as `(r1, r0)`.
+///
+/// See "Improved division by invariant integers", Algorithm 5.
+#[inline(always)]
+fn div_rem_3by2(
+    u2: ULimb,
+    u1: ULimb,
+    u0: ULimb,
+    d1: ULimb,
+    d0: ULimb,
+    v: ULimb,
+) -> (ULimb, (ULimb, ULimb)) {
+    debug_assert!((u2, u1) < (d1, d0));
+    let d = ((d1 as UWide) << ULimb::BITS) | d0 as UWide;
+    let (q0, q1) = mul_word(v, u2);
+    let (q0, carry) = q0.overflowing_add(u1);
+    let mut q1 = q1.wrapping_add(u2).wrapping_add(carry as ULimb);
+    let r1 = u1.wrapping_sub(q1.wrapping_mul(d1));
+    let t = d0 as UWide * q1 as UWide;
+    let mut r = (((r1 as UWide) << ULimb::BITS) | u0 as UWide).wrapping_sub(t).wrapping_sub(d);
+    q1 = q1.wrapping_add(1);
+    if (r >> ULimb::BITS) as ULimb >= q0 {
+        q1 = q1.wrapping_sub(1);
+        r = r.wrapping_add(d);
+    }
+    if r >= d {
+        q1 = q1.wrapping_add(1);
+        r = r.wrapping_sub(d);
+    }
+    (q1, ((r >> ULimb::BITS) as ULimb, r as ULimb))
+}
+
 /// Widening multiplication of an N-digit array with a u64
 #[inline(always)]
 const fn full_mul_u64<const N: usize>(a: &[ULimb; N], b: ULimb) -> ArrayPlusOne<N> {
@@ -603,3 +850,111 @@ impl<const N: usize> ArrayPlusOne<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NOTE: A simple LCG is enough to cover the correction branches.
+    fn next(state: &mut UWide) -> ULimb {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (*state >> (UWide::BITS - ULimb::BITS)) as ULimb
+    }
+
+    #[test]
+    fn div_rem_2by1_test() {
+        let mut state = 1;
+        for _ in 0..10000 {
+            let d = next(&mut state) | (1 << (ULimb::BITS - 1));
+            let u1 = next(&mut state) % d;
+            let u0 = next(&mut state);
+            let u = ((u1 as UWide) << ULimb::BITS) | u0 as UWide;
+            let expected = ((u / d as UWide) as ULimb, (u % d as UWide) as ULimb);
+            assert_eq!(div_rem_2by1(u1, u0, d, reciprocal_word(d)), expected);
+        }
+        let d = ULimb::MAX;
+        assert_eq!(div_rem_2by1(d - 1, ULimb::MAX, d, reciprocal_word(d)), (ULimb::MAX, d - 1));
+    }
+
+    #[test]
+    fn div_rem_3by2_test() {
+        let mut state = 2;
+        for i in 0..10000 {
+            let d1 = next(&mut state) | (1 << (ULimb::BITS - 1));
+            let d0 = match i % 3 {
+                0 => ULimb::MAX,
+                _ => next(&mut state),
+            };
+            let (u2, u1) = match i % 5 {
+                0 => (d1, d0.wrapping_sub(1)),
+                _ => (next(&mut state) % d1, next(&mut state)),
+            };
+            if (u2, u1) >= (d1, d0) {
+                continue;
+            }
+            let u0 = next(&mut state);
+            let (q, (r1, r0)) = div_rem_3by2(u2, u1, u0, d1, d0, reciprocal_3by2(d1, d0));
+
+            // `q * [d0,d1] + [r0,r1] == [u0,u1,u2]` and `[r0,r1] < [d0,d1]`
+            let numerator = [u0, u1, u2];
+            let (quotient, remainder) = full(&numerator, &[d0, d1]);
+            assert_eq!(quotient, [q, 0, 0]);
+            assert_eq!(remainder, [r0, r1]);
+        }
+    }
+
+    #[test]
+    fn limb_test() {
+        let mut state = 3;
+        for _ in 0..10000 {
+            let shift = next(&mut state) % ULimb::BITS as ULimb;
+            let d = (next(&mut state) >> shift).max(1);
+            let numerator =
+                [next(&mut state), next(&mut state), next(&mut state), next(&mut state)];
+
+            // NOTE: This is schoolbook division using the hardware division.
+            let mut expected = [0; 4];
+            let mut r: UWide = 0;
+            let mut i = 4;
+            while i > 0 {
+                i -= 1;
+                let u = (r << ULimb::BITS) | numerator[i] as UWide;
+                expected[i] = (u / d as UWide) as ULimb;
+                r = u % d as UWide;
+            }
+            assert_eq!(limb(&numerator, d), (expected, r as ULimb));
+            assert_eq!(limb_reciprocal(&numerator, d), (expected, r as ULimb));
+        }
+    }
+
+    #[test]
+    fn knuth_add_back_test() {
+        // NOTE: The divisor uses every limb, and the estimate for the high
+        // quotient digit from the top limbs is 1 too large, so the divisor
+        // must be added back to the numerator.
+        let high = 1 << (ULimb::BITS - 1);
+        let numerator = [0, 0, 0, high];
+        let divisor = [ULimb::MAX, 0, high];
+        let (quotient, remainder) = full(&numerator, &divisor);
+        assert_eq!(quotient, [ULimb::MAX, 0, 0, 0]);
+        assert_eq!(remainder, [ULimb::MAX, 1, high - 1]);
+    }
+
+    #[test]
+    fn full_const_test() {
+        let mut state = 4;
+        for i in 0..10000 {
+            let numerator =
+                [next(&mut state), next(&mut state), next(&mut state), next(&mut state)];
+            let mut divisor =
+                [next(&mut state), next(&mut state), next(&mut state), next(&mut state)];
+            let mut j = i % 4;
+            while j < 3 {
+                divisor[j + 1] = 0;
+                j += 1;
+            }
+            divisor[0] |= 1;
+            assert_eq!(full_const(&numerator, &divisor), full(&numerator, &divisor));
+        }
+    }
+}
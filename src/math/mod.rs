@@ -8,10 +8,56 @@
 // NOTE: This mostly exists for testing and is exposed for that reason.
 #![doc(hidden)]
 
+#[cfg(all(
+    target_arch = "aarch64",
+    target_endian = "little",
+    not(feature = "noasm"),
+    not(feature = "limb32")
+))]
+pub mod aarch64;
 pub mod add;
 pub mod bigint;
 pub mod div;
+pub mod mont;
 pub mod mul;
+#[cfg(all(target_arch = "x86_64", not(feature = "noasm"), not(feature = "limb32")))]
+pub mod mulx;
+pub mod prime;
+pub mod root;
 pub mod rotate;
 pub mod shift;
 pub mod sub;
+
+/// Random limbs for the differential tests of the `asm!` kernels.
+#[cfg(all(
+    test,
+    not(feature = "noasm"),
+    not(feature = "limb32"),
+    any(target_arch = "x86_64", all(target_arch = "aarch64", target_endian = "little"))
+))]
+pub(crate) mod reference {
+    /// Get the next value from a xorshift64 generator, which is good
+    /// enough for differential tests.
+    pub(crate) fn next(state: &mut u64) -> u64 {
+        let mut x = *state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        *state = x;
+        x
+    }
+
+    /// Generate 4 limbs, cycling through all bits set, only the low bits
+    /// set and random limbs with `kind`, to exercise every carry.
+    pub(crate) fn fill(state: &mut u64, kind: usize) -> [u64; 4] {
+        let mut x = [0u64; 4];
+        for xi in x.iter_mut() {
+            *xi = match kind % 4 {
+                0 => u64::MAX,
+                1 => next(state) & 0xFF,
+                _ => next(state),
+            };
+        }
+        x
+    }
+}
@@ -0,0 +1,182 @@
+//! Montgomery multiplication from small, native integer components.
+//!
+//! This implements the limb-level Montgomery reduction (REDC), using
+//! the coarsely integrated operand scanning (CIOS) method, which
+//! interleaves the multiplication and reduction steps.
+//!
+//! All arrays are in little-endian order.
+
+#![doc(hidden)]
+
+use super::bigint::{mac_u32, mac_u64};
+use crate::ULimb;
+
+macro_rules! define {
+    (type => $t:ty,neg_inv => $neg_inv:ident,mul => $mul:ident,mac => $mac:ident $(,)?) => {
+        /// Calculates `-n^-1 mod 2^BITS` for an odd `n`.
+        ///
+        /// This uses Newton's method, which doubles the number of correct
+        /// bits each iteration. Every odd value is its own inverse modulo
+        /// `8`, so our initial guess has 3 correct bits.
+        #[must_use]
+        #[inline(always)]
+        pub const fn $neg_inv(n: $t) -> $t {
+            assert!(n & 1 == 1, "modulus must be odd");
+            let mut inv = n;
+            let mut bits = 3;
+            while bits < <$t>::BITS {
+                inv = inv.wrapping_mul((2 as $t).wrapping_sub(n.wrapping_mul(inv)));
+                bits *= 2;
+            }
+            inv.wrapping_neg()
+        }
+
+        /// Montgomery multiplication, or `x * y * R^-1 mod n`, where
+        /// `R = 2^(N * BITS)`.
+        ///
+        /// This requires `x, y < n` and `neg_inv == -n^-1 mod 2^BITS`, and
+        /// the result is fully reduced.
+        #[must_use]
+        #[inline(always)]
+        pub const fn $mul<const N: usize>(
+            x: &[$t; N],
+            y: &[$t; N],
+            n: &[$t; N],
+            neg_inv: $t,
+        ) -> [$t; N] {
+            // NOTE: Our accumulator is `N + 2` limbs wide, stored as `t` and
+            // then the 2 high limbs `t_n` and `t_n1`, and is always `< 2n`.
+            let mut t: [$t; N] = [0; N];
+            let mut t_n: $t;
+            let mut t_n1: $t = 0;
+            let mut carry: $t;
+            let mut v: $t;
+
+            let mut i: usize = 0;
+            let mut j: usize;
+            while i < N {
+                // t += x * y[i]
+                carry = 0;
+                j = 0;
+                let yi = y[i];
+                while j < N {
+                    (v, carry) = $mac(t[j], x[j], yi, carry);
+                    t[j] = v;
+                    j += 1;
+                }
+                let (limb, overflowed) = t_n1.overflowing_add(carry);
+                t_n = limb;
+                t_n1 = overflowed as $t;
+
+                // t = (t + m * n) / 2^BITS, where `m` zeroes the low limb
+                let m = t[0].wrapping_mul(neg_inv);
+                (_, carry) = $mac(t[0], m, n[0], 0);
+                j = 1;
+                while j < N {
+                    (v, carry) = $mac(t[j], m, n[j], carry);
+                    t[j - 1] = v;
+                    j += 1;
+                }
+                let (limb, overflowed) = t_n.overflowing_add(carry);
+                t[N - 1] = limb;
+                t_n1 += overflowed as $t;
+                i += 1;
+            }
+
+            // the result is `< 2n`, so subtract `n` at most once
+            let mut ge = t_n1 != 0;
+            if !ge {
+                ge = true;
+                j = N;
+                while j > 0 {
+                    j -= 1;
+                    if t[j] != n[j] {
+                        ge = t[j] > n[j];
+                        break;
+                    }
+                }
+            }
+            if ge {
+                let mut borrow = false;
+                j = 0;
+                while j < N {
+                    let (limb, b0) = t[j].overflowing_sub(n[j]);
+                    let (limb, b1) = limb.overflowing_sub(borrow as $t);
+                    t[j] = limb;
+                    borrow = b0 | b1;
+                    j += 1;
+                }
+            }
+
+            t
+        }
+    };
+}
+
+define!(type => u32, neg_inv => neg_inv_u32, mul => mul_u32, mac => mac_u32);
+define!(type => u64, neg_inv => neg_inv_u64, mul => mul_u64, mac => mac_u64);
+
+/// Calculates `-n^-1 mod 2^BITS` for an odd `n`, where `BITS` is the
+/// number of bits in the limb.
+#[must_use]
+#[inline(always)]
+pub const fn neg_inv(n: ULimb) -> ULimb {
+    #[cfg(all(not(feature = "limb32"), target_pointer_width = "64"))]
+    let result = neg_inv_u64(n);
+
+    #[cfg(any(feature = "limb32", not(target_pointer_width = "64")))]
+    let result = neg_inv_u32(n);
+
+    result
+}
+
+/// Montgomery multiplication, or `x * y * R^-1 mod n`, where
+/// `R = 2^(N * BITS)` and `BITS` is the number of bits in the limb.
+#[must_use]
+#[inline(always)]
+pub const fn mul<const N: usize>(
+    x: &[ULimb; N],
+    y: &[ULimb; N],
+    n: &[ULimb; N],
+    neg_inv: ULimb,
+) -> [ULimb; N] {
+    #[cfg(all(not(feature = "limb32"), target_pointer_width = "64"))]
+    let result = mul_u64(x, y, n, neg_inv);
+
+    #[cfg(any(feature = "limb32", not(target_pointer_width = "64")))]
+    let result = mul_u32(x, y, n, neg_inv);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neg_inv_test() {
+        for n in [1u64, 3, 5, 0xffff_ffff_ffff_ffff, 0x1234_5678_9abc_def1] {
+            assert_eq!(n.wrapping_mul(neg_inv_u64(n)), u64::MAX);
+            assert_eq!((n as u32).wrapping_mul(neg_inv_u32(n as u32)), u32::MAX);
+        }
+    }
+
+    #[test]
+    fn mul_test() {
+        // n = 2^128 - 159, R = 2^128, so `R mod n == 159`.
+        let n = [0xffff_ffff_ffff_ff61u64, u64::MAX];
+        let inv = neg_inv_u64(n[0]);
+        let one = [1u64, 0];
+        let r = [159u64, 0];
+        assert_eq!(mul_u64(&r, &one, &n, inv), one);
+        assert_eq!(mul_u64(&r, &r, &n, inv), r);
+        assert_eq!(mul_u64(&[2, 0], &[3, 0], &n, inv), mul_u64(&[6, 0], &one, &n, inv));
+
+        let n = [0xffff_ff61u32, u32::MAX, u32::MAX, u32::MAX];
+        let inv = neg_inv_u32(n[0]);
+        let one = [1u32, 0, 0, 0];
+        let r = [159u32, 0, 0, 0];
+        assert_eq!(mul_u32(&r, &one, &n, inv), one);
+        assert_eq!(mul_u32(&r, &r, &n, inv), r);
+    }
+}
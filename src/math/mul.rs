@@ -615,13 +615,42 @@ limb_function!(wrapping_iscalar_i64, wrapping_ilimb_i64, wrapping_iwide_i32, &[U
 limb_function!(overflowing_uscalar_i64, overflowing_ulimb_i64, overflowing_uwide_i32, &[ULimb; N], u64, ret => ([ULimb; N], bool));
 limb_function!(overflowing_iscalar_i64, overflowing_ilimb_i64, overflowing_iwide_i32, &[ULimb; N], i64, ret => ([ULimb; N], bool));
 
+/// The minimum size of the operands in bits to use Karatsuba multiplication.
+///
+/// Below this, long multiplication is faster, since the additions and
+/// subtractions dominate the saved limb multiplications. This is only
+/// used for the widening (full) product: a wrapping multiplication only
+/// needs the low half, which long multiplication already skips, so it
+/// has fewer limb multiplications than Karatsuba.
+pub const KARATSUBA_THRESHOLD: usize = 512;
+
 macro_rules! widening_define {
-    (type => $t:ty,name => $name:ident,mac => $mac:ident $(,)?) => {
+    (
+        type =>
+        $t:ty,name =>
+        $name:ident,long =>
+        $long:ident,karatsuba =>
+        $karatsuba:ident,half =>
+        $half:ident,mac =>
+        $mac:ident $(,)?
+    ) => {
         /// Widening multiplication, which returns both the low and high bits.
         ///
         /// This cannot overflow, since all overflow is stored in the high bits.
+        /// This uses Karatsuba multiplication for operands with at least
+        /// [`KARATSUBA_THRESHOLD`] bits, and long multiplication otherwise.
         #[inline(always)]
         pub const fn $name<const M: usize>(x: &[$t; M], y: &[$t; M]) -> ([$t; M], [$t; M]) {
+            if M * <$t>::BITS as usize >= KARATSUBA_THRESHOLD && M % 2 == 0 {
+                $karatsuba(x, y)
+            } else {
+                $long(x, y)
+            }
+        }
+
+        /// Widening long multiplication, which returns both the low and high bits.
+        #[inline(always)]
+        pub const fn $long<const M: usize>(x: &[$t; M], y: &[$t; M]) -> ([$t; M], [$t; M]) {
             let mut lo: [$t; M] = [0; M];
             let mut hi: [$t; M] = [0; M];
             let mut carry: $t;
@@ -657,21 +686,487 @@ macro_rules! widening_define {
 
             (lo, hi)
         }
+
+        /// Long multiplication of the low `M / 2` limbs of `x` and `y`.
+        ///
+        /// The high limbs of `x` and `y` are ignored, and the product
+        /// fills all `M` limbs.
+        #[inline(always)]
+        const fn $half<const M: usize>(x: &[$t; M], y: &[$t; M]) -> [$t; M] {
+            let h = M / 2;
+            let mut r: [$t; M] = [0; M];
+            let mut carry: $t;
+            let mut vij: $t;
+
+            let mut i: usize = 0;
+            let mut j: usize;
+            while i < h {
+                carry = 0;
+                j = 0;
+                let xi = ne_index!(x[i]);
+                while j < h {
+                    let ij = i + j;
+                    let yj = ne_index!(y[j]);
+                    (vij, carry) = $mac(ne_index!(r[ij]), xi, yj, carry);
+                    ne_index!(r[ij] = vij);
+                    j += 1;
+                }
+                ne_index!(r[i + h] = carry);
+                i += 1;
+            }
+
+            r
+        }
+
+        /// Widening Karatsuba multiplication, which returns both the low and high bits.
+        ///
+        /// This splits each operand into halves, `x = x1*B + x0` and
+        /// `y = y1*B + y0`, and uses the subtractive variant, so the
+        /// middle term is `x0*y1 + x1*y0 = z0 + z2 + (x0 - x1)(y1 - y0)`
+        /// where `z0 = x0*y0` and `z2 = x1*y1`. This requires 3 half-sized
+        /// multiplications rather than 4. `M` must be even.
+        #[inline(always)]
+        pub const fn $karatsuba<const M: usize>(x: &[$t; M], y: &[$t; M]) -> ([$t; M], [$t; M]) {
+            let h = M / 2;
+
+            // split the operands, and get `|x0 - x1|` and `|y1 - y0|`.
+            let mut x0: [$t; M] = [0; M];
+            let mut x1: [$t; M] = [0; M];
+            let mut y0: [$t; M] = [0; M];
+            let mut y1: [$t; M] = [0; M];
+            let mut dx: [$t; M] = [0; M];
+            let mut dy: [$t; M] = [0; M];
+            let mut bx = false;
+            let mut by = false;
+            let mut i: usize = 0;
+            while i < h {
+                ne_index!(x0[i] = ne_index!(x[i]));
+                ne_index!(x1[i] = ne_index!(x[i + h]));
+                ne_index!(y0[i] = ne_index!(y[i]));
+                ne_index!(y1[i] = ne_index!(y[i + h]));
+
+                let (v, b1) = ne_index!(x0[i]).overflowing_sub(ne_index!(x1[i]));
+                let (v, b2) = v.overflowing_sub(bx as $t);
+                ne_index!(dx[i] = v);
+                bx = b1 | b2;
+
+                let (v, b1) = ne_index!(y1[i]).overflowing_sub(ne_index!(y0[i]));
+                let (v, b2) = v.overflowing_sub(by as $t);
+                ne_index!(dy[i] = v);
+                by = b1 | b2;
+                i += 1;
+            }
+
+            // NOTE: If the subtraction borrowed, the difference is negative,
+            // so take the two's complement to get the magnitude.
+            let mut cx = bx;
+            let mut cy = by;
+            i = 0;
+            while i < h {
+                if bx {
+                    let (v, c) = (!ne_index!(dx[i])).overflowing_add(cx as $t);
+                    ne_index!(dx[i] = v);
+                    cx = c;
+                }
+                if by {
+                    let (v, c) = (!ne_index!(dy[i])).overflowing_add(cy as $t);
+                    ne_index!(dy[i] = v);
+                    cy = c;
+                }
+                i += 1;
+            }
+
+            let z0 = $half(&x0, &y0);
+            let z2 = $half(&x1, &y1);
+            let p = $half(&dx, &dy);
+
+            // calculate `z1 = z0 + z2 +/- p`, where `top` is the carry limb.
+            // The middle term is `< 2*B^2`, so `top` is always 0 or 1.
+            let mut z1: [$t; M] = [0; M];
+            let mut top: $t = 0;
+            let mut c = false;
+            i = 0;
+            while i < M {
+                let (v, c1) = ne_index!(z0[i]).overflowing_add(ne_index!(z2[i]));
+                let (v, c2) = v.overflowing_add(c as $t);
+                ne_index!(z1[i] = v);
+                c = c1 | c2;
+                i += 1;
+            }
+            top += c as $t;
+
+            c = false;
+            i = 0;
+            if bx != by {
+                while i < M {
+                    let (v, b1) = ne_index!(z1[i]).overflowing_sub(ne_index!(p[i]));
+                    let (v, b2) = v.overflowing_sub(c as $t);
+                    ne_index!(z1[i] = v);
+                    c = b1 | b2;
+                    i += 1;
+                }
+                top = top.wrapping_sub(c as $t);
+            } else {
+                while i < M {
+                    let (v, c1) = ne_index!(z1[i]).overflowing_add(ne_index!(p[i]));
+                    let (v, c2) = v.overflowing_add(c as $t);
+                    ne_index!(z1[i] = v);
+                    c = c1 | c2;
+                    i += 1;
+                }
+                top = top.wrapping_add(c as $t);
+            }
+
+            // add `z1*B` to `z2*B^2 + z0`, propagating the carry to the top.
+            let mut lo = z0;
+            let mut hi = z2;
+            c = false;
+            i = 0;
+            while i < M + h {
+                let k = i + h;
+                let zi = if i < M {
+                    ne_index!(z1[i])
+                } else if i == M {
+                    top
+                } else {
+                    0
+                };
+                if k < M {
+                    let (v, c1) = ne_index!(lo[k]).overflowing_add(zi);
+                    let (v, c2) = v.overflowing_add(c as $t);
+                    ne_index!(lo[k] = v);
+                    c = c1 | c2;
+                } else {
+                    let (v, c1) = ne_index!(hi[k - M]).overflowing_add(zi);
+                    let (v, c2) = v.overflowing_add(c as $t);
+                    ne_index!(hi[k - M] = v);
+                    c = c1 | c2;
+                }
+                i += 1;
+            }
+
+            (lo, hi)
+        }
     };
 }
 
 widening_define!(
     type => u32,
     name => widening_u32,
+    long => widening_long_u32,
+    karatsuba => karatsuba_u32,
+    half => half_u32,
     mac => mac_u32,
 );
 widening_define!(
     type => u64,
     name => widening_u64,
+    long => widening_long_u64,
+    karatsuba => karatsuba_u64,
+    half => half_u64,
     mac => mac_u64,
 );
 
 limb_function!(widening, widening_u64, widening_u32, &[ULimb; N], ret => ([ULimb; N], [ULimb; N]));
+limb_function!(widening_long, widening_long_u64, widening_long_u32, &[ULimb; N], ret => ([ULimb; N], [ULimb; N]));
+limb_function!(karatsuba, karatsuba_u64, karatsuba_u32, &[ULimb; N], ret => ([ULimb; N], [ULimb; N]));
+
+// NOTE: The `asm!` kernels are only defined for 4 limbs, so the non-`const`
+// versions below check the number of limbs, which is a constant and so the
+// other branch is optimized out, and then convert the arrays to the fixed
+// size. Every other size uses the portable algorithms.
+
+/// Non-`const` version of [`wrapping_unsigned`] for operands of the same size.
+///
+/// With the `bmi2` and `adx` target features on `x86_64`, 4-limb operands
+/// use `mulx::wrapping`. On little-endian `aarch64`, they use
+/// `aarch64::wrapping_mul`.
+#[inline(always)]
+pub fn wrapping_runtime<const N: usize>(x: &[ULimb; N], y: &[ULimb; N]) -> [ULimb; N] {
+    #[cfg(all(
+        target_arch = "x86_64",
+        target_feature = "bmi2",
+        target_feature = "adx",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        // SAFETY: Safe, since the target features are enabled.
+        let r = unsafe { super::mulx::wrapping(x4, y4) };
+        if let Ok(r) = <[ULimb; N]>::try_from(&r[..]) {
+            return r;
+        }
+    }
+
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_endian = "little",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        let r = super::aarch64::wrapping_mul(x4, y4);
+        if let Ok(r) = <[ULimb; N]>::try_from(&r[..]) {
+            return r;
+        }
+    }
+
+    wrapping_unsigned(x, y)
+}
+
+/// Non-`const` version of [`wrapping_limb`].
+///
+/// With the `bmi2` target feature on `x86_64`, 4-limb operands use
+/// `mulx::wrapping_limb`.
+#[inline(always)]
+pub fn wrapping_limb_runtime<const N: usize>(x: &[ULimb; N], y: ULimb) -> [ULimb; N] {
+    #[cfg(all(
+        target_arch = "x86_64",
+        target_feature = "bmi2",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let Ok(x4) = <&[u64; 4]>::try_from(&x[..]) {
+        // SAFETY: Safe, since the target feature is enabled.
+        let r = unsafe { super::mulx::wrapping_limb(x4, y) };
+        if let Ok(r) = <[ULimb; N]>::try_from(&r[..]) {
+            return r;
+        }
+    }
+
+    wrapping_limb(x, y)
+}
+
+/// Non-`const` version of [`widening`].
+///
+/// With the `bmi2` and `adx` target features on `x86_64`, 4-limb operands
+/// use `mulx::widening`. On little-endian `aarch64`, they use
+/// `aarch64::widening_mul`.
+#[inline(always)]
+pub fn widening_runtime<const N: usize>(
+    x: &[ULimb; N],
+    y: &[ULimb; N],
+) -> ([ULimb; N], [ULimb; N]) {
+    #[cfg(all(
+        target_arch = "x86_64",
+        target_feature = "bmi2",
+        target_feature = "adx",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        // SAFETY: Safe, since the target features are enabled.
+        let (lo, hi) = unsafe { super::mulx::widening(x4, y4) };
+        if let (Ok(lo), Ok(hi)) = (<[ULimb; N]>::try_from(&lo[..]), <[ULimb; N]>::try_from(&hi[..]))
+        {
+            return (lo, hi);
+        }
+    }
+
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_endian = "little",
+        not(feature = "noasm"),
+        not(feature = "limb32")
+    ))]
+    if let (Ok(x4), Ok(y4)) = (<&[u64; 4]>::try_from(&x[..]), <&[u64; 4]>::try_from(&y[..])) {
+        let (lo, hi) = super::aarch64::widening_mul(x4, y4);
+        if let (Ok(lo), Ok(hi)) = (<[ULimb; N]>::try_from(&lo[..]), <[ULimb; N]>::try_from(&hi[..]))
+        {
+            return (lo, hi);
+        }
+    }
+
+    widening(x, y)
+}
+
+macro_rules! square_define {
+    (
+        type =>
+        $t:ty,wrapping =>
+        $wrapping:ident,widening =>
+        $widening:ident,mac =>
+        $mac:ident $(,)?
+    ) => {
+        /// Const implementation of `wrapping_square` for internal algorithm use.
+        ///
+        /// Returns the value, wrapping on overflow. Each cross product
+        /// `x[i] * x[j]` for `i < j` is only calculated once and then doubled,
+        /// before the squares of each limb are added, so this requires
+        /// about half the limb multiplications of [`wrapping_mul`].
+        ///
+        /// [`wrapping_mul`]: crate::math::mul::wrapping_unsigned
+        #[must_use]
+        #[inline(always)]
+        pub const fn $wrapping<const M: usize>(x: &[$t; M]) -> [$t; M] {
+            let mut r: [$t; M] = [0; M];
+            let mut carry: $t;
+            let mut vij: $t;
+
+            // calculate the cross products, `x[i] * x[j]` for `i < j`.
+            let mut i: usize = 0;
+            let mut j: usize;
+            while i < M {
+                carry = 0;
+                j = i + 1;
+                let xi = ne_index!(x[i]);
+                while i + j < M {
+                    let ij = i + j;
+                    let xj = ne_index!(x[j]);
+                    (vij, carry) = $mac(ne_index!(r[ij]), xi, xj, carry);
+                    ne_index!(r[ij] = vij);
+                    j += 1;
+                }
+                i += 1;
+            }
+
+            // double the cross products.
+            i = M;
+            while i > 1 {
+                i -= 1;
+                let hi = ne_index!(r[i]) << 1;
+                let lo = ne_index!(r[i - 1]) >> (<$t>::BITS - 1);
+                ne_index!(r[i] = hi | lo);
+            }
+            if M > 0 {
+                ne_index!(r[0] = ne_index!(r[0]) << 1);
+            }
+
+            // add the squares, `x[i] * x[i]`.
+            carry = 0;
+            i = 0;
+            while 2 * i < M {
+                let xi = ne_index!(x[i]);
+                let (lo, hi) = $mac(ne_index!(r[2 * i]), xi, xi, carry);
+                ne_index!(r[2 * i] = lo);
+                if 2 * i + 1 < M {
+                    let (v, c) = ne_index!(r[2 * i + 1]).overflowing_add(hi);
+                    ne_index!(r[2 * i + 1] = v);
+                    carry = c as $t;
+                }
+                i += 1;
+            }
+
+            r
+        }
+
+        /// Widening squaring, which returns both the low and high bits.
+        ///
+        /// This cannot overflow, since all overflow is stored in the high bits.
+        /// This uses the same algorithm as [`wrapping_square`], with about
+        /// half the limb multiplications of [`widening`].
+        ///
+        /// [`wrapping_square`]: crate::math::mul::wrapping_square
+        /// [`widening`]: crate::math::mul::widening
+        #[must_use]
+        #[inline(always)]
+        pub const fn $widening<const M: usize>(x: &[$t; M]) -> ([$t; M], [$t; M]) {
+            let mut lo: [$t; M] = [0; M];
+            let mut hi: [$t; M] = [0; M];
+            let mut carry: $t;
+            let mut vij: $t;
+
+            // calculate the cross products, `x[i] * x[j]` for `i < j`.
+            let mut i: usize = 0;
+            let mut j: usize;
+            while i < M {
+                carry = 0;
+                j = i + 1;
+                let xi = ne_index!(x[i]);
+                while j < M {
+                    let ij = i + j;
+                    let xj = ne_index!(x[j]);
+                    if ij < M {
+                        (vij, carry) = $mac(ne_index!(lo[ij]), xi, xj, carry);
+                        ne_index!(lo[ij] = vij);
+                    } else {
+                        (vij, carry) = $mac(ne_index!(hi[ij - M]), xi, xj, carry);
+                        ne_index!(hi[ij - M] = vij);
+                    }
+                    j += 1;
+                }
+                // NOTE: `i + M >= M`, so the carry is always in the high bits.
+                if i + 1 < M {
+                    ne_index!(hi[i] = carry);
+                }
+                i += 1;
+            }
+
+            // double the cross products.
+            i = M;
+            while i > 1 {
+                i -= 1;
+                let v = ne_index!(hi[i]) << 1;
+                let c = ne_index!(hi[i - 1]) >> (<$t>::BITS - 1);
+                ne_index!(hi[i] = v | c);
+            }
+            if M > 0 {
+                let v = ne_index!(hi[0]) << 1;
+                let c = ne_index!(lo[M - 1]) >> (<$t>::BITS - 1);
+                ne_index!(hi[0] = v | c);
+            }
+            i = M;
+            while i > 1 {
+                i -= 1;
+                let v = ne_index!(lo[i]) << 1;
+                let c = ne_index!(lo[i - 1]) >> (<$t>::BITS - 1);
+                ne_index!(lo[i] = v | c);
+            }
+            if M > 0 {
+                ne_index!(lo[0] = ne_index!(lo[0]) << 1);
+            }
+
+            // add the squares, `x[i] * x[i]`.
+            carry = 0;
+            i = 0;
+            while i < M {
+                let xi = ne_index!(x[i]);
+                let k = 2 * i;
+                let (v, h) = if k < M {
+                    $mac(ne_index!(lo[k]), xi, xi, carry)
+                } else {
+                    $mac(ne_index!(hi[k - M]), xi, xi, carry)
+                };
+                if k < M {
+                    ne_index!(lo[k] = v);
+                } else {
+                    ne_index!(hi[k - M] = v);
+                }
+
+                let (v, c) = if k + 1 < M {
+                    ne_index!(lo[k + 1]).overflowing_add(h)
+                } else {
+                    ne_index!(hi[k + 1 - M]).overflowing_add(h)
+                };
+                if k + 1 < M {
+                    ne_index!(lo[k + 1] = v);
+                } else {
+                    ne_index!(hi[k + 1 - M] = v);
+                }
+                carry = c as $t;
+                i += 1;
+            }
+
+            (lo, hi)
+        }
+    };
+}
+
+square_define!(
+    type => u32,
+    wrapping => wrapping_square_u32,
+    widening => widening_square_u32,
+    mac => mac_u32,
+);
+square_define!(
+    type => u64,
+    wrapping => wrapping_square_u64,
+    widening => widening_square_u64,
+    mac => mac_u64,
+);
+
+limb_function!(unary wrapping_square, wrapping_square_u64, wrapping_square_u32, &[ULimb; N], ret => [ULimb; N]);
+limb_function!(unary widening_square, widening_square_u64, widening_square_u32, &[ULimb; N], ret => ([ULimb; N], [ULimb; N]));
 
 #[cfg(test)]
 mod tests {
@@ -827,4 +1322,85 @@ mod tests {
             ([1, 0], [u32::MAX - 1, u32::MAX])
         );
     }
+
+    fn next(state: &mut u64) -> u64 {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        *state
+    }
+
+    #[test]
+    fn karatsuba_test() {
+        assert_eq!(
+            karatsuba_u64(&[u64::MAX; 8], &[u64::MAX; 8]),
+            widening_long_u64(&[u64::MAX; 8], &[u64::MAX; 8])
+        );
+        assert_eq!(
+            karatsuba_u32(&[u32::MAX; 16], &[u32::MAX; 16]),
+            widening_long_u32(&[u32::MAX; 16], &[u32::MAX; 16])
+        );
+
+        let mut state = 1;
+        for i in 0..1000 {
+            let mut x = [0u64; 16];
+            let mut y = [0u64; 16];
+            let mut x32 = [0u32; 16];
+            let mut y32 = [0u32; 16];
+            for j in 0..16 {
+                // NOTE: Mask some of the limbs so the halves are sometimes equal.
+                x[j] = if i % 3 == 0 {
+                    next(&mut state) & 3
+                } else {
+                    next(&mut state)
+                };
+                y[j] = if i % 5 == 0 {
+                    u64::MAX
+                } else {
+                    next(&mut state)
+                };
+                x32[j] = x[j] as u32;
+                y32[j] = (y[j] >> 32) as u32;
+            }
+            assert_eq!(karatsuba_u64(&x, &y), widening_long_u64(&x, &y));
+            assert_eq!(karatsuba_u32(&x32, &y32), widening_long_u32(&x32, &y32));
+            assert_eq!(karatsuba_u32(&x32, &x32), widening_long_u32(&x32, &x32));
+        }
+    }
+
+    #[test]
+    fn square_test() {
+        assert_eq!(
+            wrapping_square_u32(&[u32::MAX; 3]),
+            wrapping_u32(&[u32::MAX; 3], &[u32::MAX; 3])
+        );
+        assert_eq!(
+            widening_square_u32(&[u32::MAX; 3]),
+            widening_long_u32(&[u32::MAX; 3], &[u32::MAX; 3])
+        );
+        assert_eq!(
+            widening_square_u64(&[u64::MAX; 4]),
+            widening_long_u64(&[u64::MAX; 4], &[u64::MAX; 4])
+        );
+
+        let mut state = 2;
+        for i in 0..1000 {
+            let mut x = [0u64; 5];
+            let mut x32 = [0u32; 5];
+            for j in 0..5 {
+                x[j] = if i % 3 == 0 {
+                    u64::MAX
+                } else {
+                    next(&mut state)
+                };
+                x32[j] = x[j] as u32;
+            }
+            assert_eq!(wrapping_square_u64(&x), wrapping_u64(&x, &x));
+            assert_eq!(widening_square_u64(&x), widening_long_u64(&x, &x));
+            assert_eq!(wrapping_square_u32(&x32), wrapping_u32(&x32, &x32));
+            assert_eq!(widening_square_u32(&x32), widening_long_u32(&x32, &x32));
+
+            let x4 = [x[0], x[1], x[2], x[3]];
+            assert_eq!(wrapping_square_u64(&x4), wrapping_u64(&x4, &x4));
+            assert_eq!(widening_square_u64(&x4), widening_long_u64(&x4, &x4));
+        }
+    }
 }
@@ -0,0 +1,239 @@
+//! Multiplication kernels for `x86_64` using `mulx`, `adcx` and `adox`.
+//!
+//! These use 2 independent carry chains for each row of a long
+//! multiplication: `adcx` adds the low half of each limb product
+//! using the carry flag, and `adox` adds the high half of the previous
+//! limb product using the overflow flag. Since `mulx` doesn't modify
+//! any flags, the 2 chains never have to be serialized.
+//!
+//! These are only defined for 256-bit operands, that is, 4 limbs, and
+//! require the `bmi2` and `adx` target features. When both are enabled
+//! at compile time, the non-`const` multiplications in
+//! [`mul`][super::mul] use them for 4-limb operands.
+
+#![doc(hidden)]
+
+use core::arch::asm;
+
+/// Wrapping multiplication of 4 limbs, using the `mulx`, `adcx` and `adox`
+/// instructions.
+///
+/// This has the same semantics as [`wrapping_u64`][super::mul::wrapping_u64].
+///
+/// # Safety
+///
+/// The CPU must support the `bmi2` and `adx` features.
+#[inline(always)]
+pub unsafe fn wrapping(x: &[u64; 4], y: &[u64; 4]) -> [u64; 4] {
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // NOTE: The first row is a single carry chain, since there is nothing to
+    // add the low halves to, and only the low halves of the last product in
+    // each row are needed. The `xor` clears both the carry and overflow
+    // flags, and neither `mov` nor `mulx` modify the flags.
+    // SAFETY: Safe as long as the CPU supports the required features.
+    unsafe {
+        asm!(
+            "mov rdx, qword ptr [{x}]",
+            "mulx {a1}, {a0}, qword ptr [{y}]",
+            "mulx {a2}, {lo}, qword ptr [{y} + 8]",
+            "add {a1}, {lo}",
+            "mulx {a3}, {lo}, qword ptr [{y} + 16]",
+            "adc {a2}, {lo}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 24]",
+            "adc {a3}, {lo}",
+            "mov rdx, qword ptr [{x} + 8]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "adcx {a1}, {lo}",
+            "adox {a2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 8]",
+            "adcx {a2}, {lo}",
+            "adox {a3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 16]",
+            "adcx {a3}, {lo}",
+            "mov rdx, qword ptr [{x} + 16]",
+            "xor {lo:e}, {lo:e}",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "adcx {a2}, {lo}",
+            "adox {a3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 8]",
+            "adcx {a3}, {lo}",
+            "mov rdx, qword ptr [{x} + 24]",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "add {a3}, {lo}",
+            x = in(reg) x.as_ptr(),
+            y = in(reg) y.as_ptr(),
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            out("rdx") _,
+            options(pure, readonly, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// Wrapping multiplication of 4 limbs by a single limb, using the `mulx`
+/// instruction.
+///
+/// This has the same semantics as
+/// [`wrapping_limb_u64`][super::mul::wrapping_limb_u64].
+///
+/// # Safety
+///
+/// The CPU must support the `bmi2` feature.
+#[inline(always)]
+pub unsafe fn wrapping_limb(x: &[u64; 4], y: u64) -> [u64; 4] {
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // NOTE: There is only a single row, so this only needs the carry flag,
+    // and only the low half of the last product is needed.
+    // SAFETY: Safe as long as the CPU supports the required features.
+    unsafe {
+        asm!(
+            "mulx {a1}, {a0}, qword ptr [{x}]",
+            "mulx {a2}, {lo}, qword ptr [{x} + 8]",
+            "add {a1}, {lo}",
+            "mulx {a3}, {lo}, qword ptr [{x} + 16]",
+            "adc {a2}, {lo}",
+            "mulx {hi}, {lo}, qword ptr [{x} + 24]",
+            "adc {a3}, {lo}",
+            x = in(reg) x.as_ptr(),
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            in("rdx") y,
+            options(pure, readonly, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// Widening multiplication of 4 limbs, which returns both the low and high
+/// bits, using the `mulx`, `adcx` and `adox` instructions.
+///
+/// This has the same semantics as
+/// [`widening_long_u64`][super::mul::widening_long_u64].
+///
+/// # Safety
+///
+/// The CPU must support the `bmi2` and `adx` features.
+#[inline(always)]
+pub unsafe fn widening(x: &[u64; 4], y: &[u64; 4]) -> ([u64; 4], [u64; 4]) {
+    let (r0, r1, r2, r3, r4, r5, r6, r7): (u64, u64, u64, u64, u64, u64, u64, u64);
+    // NOTE: Each row adds the low halves of the products using the carry
+    // flag and the high halves using the overflow flag, into the next limb
+    // of the product, which the `xor` zeroes while clearing both flags. The
+    // final carry of each row can't overflow, since the product of 2 limbs
+    // plus 2 limbs always fits in 2 limbs. This uses every register that
+    // isn't reserved, so the product is never spilled.
+    // SAFETY: Safe as long as the CPU supports the required features.
+    unsafe {
+        asm!(
+            "mov rdx, qword ptr [{x}]",
+            "mulx {a1}, {a0}, qword ptr [{y}]",
+            "mulx {a2}, {lo}, qword ptr [{y} + 8]",
+            "add {a1}, {lo}",
+            "mulx {a3}, {lo}, qword ptr [{y} + 16]",
+            "adc {a2}, {lo}",
+            "mulx {a4}, {lo}, qword ptr [{y} + 24]",
+            "adc {a3}, {lo}",
+            "adc {a4}, 0",
+            "mov rdx, qword ptr [{x} + 8]",
+            "xor {a5:e}, {a5:e}",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "adcx {a1}, {lo}",
+            "adox {a2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 8]",
+            "adcx {a2}, {lo}",
+            "adox {a3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 16]",
+            "adcx {a3}, {lo}",
+            "adox {a4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 24]",
+            "adcx {a4}, {lo}",
+            "adox {a5}, {hi}",
+            "mov {lo:e}, 0",
+            "adcx {a5}, {lo}",
+            "mov rdx, qword ptr [{x} + 16]",
+            "xor {a6:e}, {a6:e}",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "adcx {a2}, {lo}",
+            "adox {a3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 8]",
+            "adcx {a3}, {lo}",
+            "adox {a4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 16]",
+            "adcx {a4}, {lo}",
+            "adox {a5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 24]",
+            "adcx {a5}, {lo}",
+            "adox {a6}, {hi}",
+            "mov {lo:e}, 0",
+            "adcx {a6}, {lo}",
+            "mov rdx, qword ptr [{x} + 24]",
+            "xor {a7:e}, {a7:e}",
+            "mulx {hi}, {lo}, qword ptr [{y}]",
+            "adcx {a3}, {lo}",
+            "adox {a4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 8]",
+            "adcx {a4}, {lo}",
+            "adox {a5}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 16]",
+            "adcx {a5}, {lo}",
+            "adox {a6}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{y} + 24]",
+            "adcx {a6}, {lo}",
+            "adox {a7}, {hi}",
+            "mov {lo:e}, 0",
+            "adcx {a7}, {lo}",
+            x = in(reg) x.as_ptr(),
+            y = in(reg) y.as_ptr(),
+            a0 = out(reg) r0,
+            a1 = out(reg) r1,
+            a2 = out(reg) r2,
+            a3 = out(reg) r3,
+            a4 = out(reg) r4,
+            a5 = out(reg) r5,
+            a6 = out(reg) r6,
+            a7 = out(reg) r7,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            out("rdx") _,
+            options(pure, readonly, nostack),
+        );
+    }
+    ([r0, r1, r2, r3], [r4, r5, r6, r7])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::mul::{widening_long_u64, wrapping_limb_u64, wrapping_u64};
+    use crate::math::reference::fill;
+
+    #[test]
+    fn differential_test() {
+        if !(is_x86_feature_detected!("bmi2") && is_x86_feature_detected!("adx")) {
+            return;
+        }
+
+        let mut state = 0x9E37_79B9_7F4A_7C15;
+        for i in 0..20000 {
+            let x = fill(&mut state, i);
+            let y = fill(&mut state, i / 4);
+
+            // SAFETY: Safe, since we've checked the CPU features.
+            unsafe {
+                assert_eq!(wrapping(&x, &y), wrapping_u64(&x, &y));
+                assert_eq!(wrapping_limb(&x, y[0]), wrapping_limb_u64(&x, y[0]));
+                assert_eq!(widening(&x, &y), widening_long_u64(&x, &y));
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Number-theoretic utilities for native integers.
+//!
+//! These are used for the primality tests of our big integers, where
+//! the values that are tested against are always small.
+
+/// The odd primes less than 256, used for trial division.
+pub const SMALL_PRIMES: [u8; 53] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// Calculate the Jacobi symbol `(a / n)` of 64-bit integers.
+///
+/// This uses the law of quadratic reciprocity, removing any factors
+/// of 2 from the numerator at each step.
+///
+/// # Panics
+///
+/// This panics if `n` is even.
+#[inline]
+pub const fn jacobi_u64(a: u64, n: u64) -> i32 {
+    assert!(n % 2 == 1, "Jacobi symbol requires an odd denominator");
+    let mut a = a % n;
+    let mut n = n;
+    let mut result = 1;
+    while a != 0 {
+        // NOTE: `(2 / n) == -1` if and only if `n == 3 or 5 mod 8`.
+        let zeros = a.trailing_zeros();
+        a >>= zeros;
+        if zeros % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            result = -result;
+        }
+        // NOTE: `(a / n) == -(n / a)` if and only if `a == n == 3 mod 4`.
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        let t = a;
+        a = n % t;
+        n = t;
+    }
+
+    match n {
+        1 => result,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_primes_test() {
+        let mut i = 0;
+        for n in 3u32..256 {
+            if (2..n).all(|d| n % d != 0) {
+                assert_eq!(SMALL_PRIMES[i] as u32, n);
+                i += 1;
+            }
+        }
+        assert_eq!(i, SMALL_PRIMES.len());
+    }
+
+    #[test]
+    fn jacobi_u64_test() {
+        assert_eq!(jacobi_u64(0, 1), 1);
+        assert_eq!(jacobi_u64(0, 3), 0);
+        assert_eq!(jacobi_u64(1, 3), 1);
+        assert_eq!(jacobi_u64(2, 3), -1);
+        assert_eq!(jacobi_u64(2, 7), 1);
+        assert_eq!(jacobi_u64(5, 21), 1);
+        assert_eq!(jacobi_u64(8, 21), -1);
+        assert_eq!(jacobi_u64(1001, 9907), -1);
+        assert_eq!(jacobi_u64(19, 45), 1);
+        assert_eq!(jacobi_u64(30, 57), 0);
+
+        // Euler's criterion for a prime modulus.
+        let p = 1_000_000_007u64;
+        for a in [2u64, 3, 5, 7, 1234567, p - 1] {
+            let mut power = 1u128;
+            let mut base = a as u128;
+            let mut exp = (p - 1) / 2;
+            while exp != 0 {
+                if exp & 1 == 1 {
+                    power = power * base % p as u128;
+                }
+                base = base * base % p as u128;
+                exp >>= 1;
+            }
+            let expected = if power == 1 {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(jacobi_u64(a, p), expected);
+        }
+    }
+}
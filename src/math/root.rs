@@ -0,0 +1,108 @@
+//! Integer roots of native integers.
+//!
+//! These are exact for the native types, and are used to seed the
+//! Newton iterations for the roots of our big integers.
+
+/// Calculate the square root of a 64-bit integer, rounded down.
+///
+/// This uses Newton's method with an initial estimate larger than
+/// the root, so the estimates decrease monotonically to the result.
+#[inline]
+pub const fn isqrt_u64(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // `2^(floor(log2(n) / 2) + 1)` is always larger than the root.
+    let mut x = 1u64 << ((u64::BITS - 1 - n.leading_zeros()) / 2 + 1);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Calculate the `n`th root of a 64-bit integer, rounded down.
+///
+/// This uses Newton's method with an initial estimate larger than
+/// the root, so the estimates decrease monotonically to the result.
+///
+/// # Panics
+///
+/// This panics if `n` is 0.
+#[inline]
+pub const fn iroot_u64(x: u64, n: u32) -> u64 {
+    assert!(n != 0, "degree of integer root must be positive");
+    let bits = u64::BITS - x.leading_zeros();
+    if n == 1 || x < 2 {
+        return x;
+    } else if n >= bits {
+        return 1;
+    }
+
+    // `2^ceil(bits / n)` is always larger than the root.
+    let mut r = 1u64 << ((bits + n - 1) / n);
+    loop {
+        // NOTE: If `r^(n - 1)` overflows, then `x / r^(n - 1)` is 0.
+        let q = match r.checked_pow(n - 1) {
+            Some(p) => x / p,
+            None => 0,
+        };
+        let y = ((n as u64 - 1) * r + q) / n as u64;
+        if y >= r {
+            return r;
+        }
+        r = y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_isqrt(n: u64, root: u64) -> bool {
+        let lo = root as u128 * root as u128;
+        let hi = (root as u128 + 1) * (root as u128 + 1);
+        lo <= n as u128 && (n as u128) < hi
+    }
+
+    #[test]
+    fn isqrt_u64_test() {
+        assert_eq!(isqrt_u64(0), 0);
+        assert_eq!(isqrt_u64(1), 1);
+        assert_eq!(isqrt_u64(3), 1);
+        assert_eq!(isqrt_u64(4), 2);
+        assert_eq!(isqrt_u64(u64::MAX), u32::MAX as u64);
+        for n in (0..64).flat_map(|i| [(1u64 << i) - 1, 1 << i, (1 << i) + 1]) {
+            assert!(is_isqrt(n, isqrt_u64(n)));
+        }
+        for r in [3u64, 255, 65535, 0xFFFF_FFFF] {
+            assert_eq!(isqrt_u64(r * r), r);
+            assert_eq!(isqrt_u64(r * r - 1), r - 1);
+        }
+    }
+
+    #[test]
+    fn iroot_u64_test() {
+        assert_eq!(iroot_u64(0, 3), 0);
+        assert_eq!(iroot_u64(1, 7), 1);
+        assert_eq!(iroot_u64(7, 1), 7);
+        assert_eq!(iroot_u64(26, 3), 2);
+        assert_eq!(iroot_u64(27, 3), 3);
+        assert_eq!(iroot_u64(u64::MAX, 3), 2642245);
+        assert_eq!(iroot_u64(u64::MAX, 63), 2);
+        assert_eq!(iroot_u64(u64::MAX, 64), 1);
+        assert_eq!(iroot_u64(1 << 63, 63), 2);
+        assert_eq!(iroot_u64((1 << 63) - 1, 63), 1);
+        for n in 2..64 {
+            for x in [3u64, 1000, 1 << 40, u64::MAX / 3, u64::MAX] {
+                let root = iroot_u64(x, n);
+                assert!(root.pow(n) as u128 <= x as u128);
+                assert!((root as u128 + 1).checked_pow(n).map_or(true, |v| v > x as u128));
+            }
+        }
+        assert_eq!(iroot_u64(u64::MAX, 2), isqrt_u64(u64::MAX));
+    }
+}
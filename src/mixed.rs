@@ -0,0 +1,119 @@
+//! Arithmetic between our unsigned integer types of different widths.
+//!
+//! These use the kernels for operands with different numbers of limbs
+//! directly, so the narrower operand doesn't need to be zero-extended,
+//! and the narrower remainder is returned from division.
+
+// NOTE: These are only defined if there are multiple widths.
+#![cfg_attr(
+    not(any(feature = "i384", feature = "i512", feature = "i1024")),
+    allow(unused_macros, unused_imports)
+)]
+
+#[rustfmt::skip]
+macro_rules! define {
+    (
+        type => $t:ty,
+        rhs => $rhs:ty,
+        wrapping_add => $wrapping_add:ident,
+        overflowing_add => $overflowing_add:ident,
+        wrapping_sub => $wrapping_sub:ident,
+        overflowing_sub => $overflowing_sub:ident,
+        wrapping_mul => $wrapping_mul:ident,
+        overflowing_mul => $overflowing_mul:ident,
+        div_rem => $div_rem:ident,
+        div => $div:ident,
+        rem => $rem:ident $(,)?
+    ) => {
+        impl $t {
+            #[doc = concat!("Add a [`", stringify!($rhs), "`] to the big integer, wrapping on overflow.")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $wrapping_add(self, n: $rhs) -> Self {
+                let limbs = $crate::math::add::wrapping_mn(&self.to_ne_limbs(), &n.to_ne_limbs());
+                Self::from_ne_limbs(limbs)
+            }
+
+            #[doc = concat!("Add a [`", stringify!($rhs), "`] to the big integer, returning the value")]
+            /// and if overflow occurred.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $overflowing_add(self, n: $rhs) -> (Self, bool) {
+                let (limbs, overflowed) = $crate::math::add::overflowing_mn(&self.to_ne_limbs(), &n.to_ne_limbs());
+                (Self::from_ne_limbs(limbs), overflowed)
+            }
+
+            #[doc = concat!("Subtract a [`", stringify!($rhs), "`] from the big integer, wrapping on overflow.")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $wrapping_sub(self, n: $rhs) -> Self {
+                let limbs = $crate::math::sub::wrapping_mn(&self.to_ne_limbs(), &n.to_ne_limbs());
+                Self::from_ne_limbs(limbs)
+            }
+
+            #[doc = concat!("Subtract a [`", stringify!($rhs), "`] from the big integer, returning the value")]
+            /// and if overflow occurred.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $overflowing_sub(self, n: $rhs) -> (Self, bool) {
+                let (limbs, overflowed) = $crate::math::sub::overflowing_mn(&self.to_ne_limbs(), &n.to_ne_limbs());
+                (Self::from_ne_limbs(limbs), overflowed)
+            }
+
+            #[doc = concat!("Multiply the big integer by a [`", stringify!($rhs), "`], wrapping on overflow.")]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $wrapping_mul(self, n: $rhs) -> Self {
+                let limbs = $crate::math::mul::wrapping_unsigned(&self.to_ne_limbs(), &n.to_ne_limbs());
+                Self::from_ne_limbs(limbs)
+            }
+
+            #[doc = concat!("Multiply the big integer by a [`", stringify!($rhs), "`], returning the value")]
+            /// and if overflow occurred.
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn $overflowing_mul(self, n: $rhs) -> (Self, bool) {
+                let (limbs, overflowed) = $crate::math::mul::overflowing_unsigned(&self.to_ne_limbs(), &n.to_ne_limbs());
+                (Self::from_ne_limbs(limbs), overflowed)
+            }
+
+            #[doc = concat!("Get the quotient and remainder of the big integer divided by a [`", stringify!($rhs), "`].")]
+            ///
+            /// The remainder is always less than the divisor, so it is returned
+            /// as the narrower type.
+            ///
+            #[doc = $crate::shared::docs::div_by_zero_doc!(n)]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub fn $div_rem(self, n: $rhs) -> (Self, $rhs) {
+                assert!(!n.eq_const(<$rhs>::MIN), "attempt to divide by zero");
+                // NOTE: Our algorithm assumes little-endian order, which we might not have.
+                let (div, rem) = $crate::math::div::full(&self.to_le_limbs(), &n.to_le_limbs());
+                (Self::from_le_limbs(div), <$rhs>::from_le_limbs(rem))
+            }
+
+            #[doc = concat!("Get the quotient of the big integer divided by a [`", stringify!($rhs), "`].")]
+            ///
+            #[doc = $crate::shared::docs::div_by_zero_doc!(n)]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub fn $div(self, n: $rhs) -> Self {
+                self.$div_rem(n).0
+            }
+
+            #[doc = concat!("Get the remainder of the big integer divided by a [`", stringify!($rhs), "`].")]
+            ///
+            /// This can be used to reduce a wide value, such as a product, by a
+            /// narrower modulus.
+            ///
+            #[doc = $crate::shared::docs::div_by_zero_doc!(n)]
+            #[inline(always)]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub fn $rem(self, n: $rhs) -> $rhs {
+                self.$div_rem(n).1
+            }
+        }
+    };
+}
+
+pub(crate) use define;
@@ -0,0 +1,181 @@
+//! Montgomery multiplication for a runtime, odd modulus.
+//!
+//! Values are converted into the Montgomery form `x * R mod N`, where
+//! `R = 2^BITS`, which allows modular multiplication to be calculated
+//! with a reduction that only uses multiplications, additions and
+//! shifts, rather than division. This is significantly faster when many
+//! operations are performed with the same modulus, such as for modular
+//! exponentiation.
+
+use crate::ULimb;
+
+/// A precomputed context for Montgomery multiplication.
+///
+/// This is created from an odd modulus `N`, and stores `R mod N`,
+/// `R^2 mod N` and `-N^-1 mod 2^LIMB_BITS`, where `R = 2^BITS` and
+/// `LIMB_BITS` is the number of bits in a [`ULimb`]. The operations
+/// such as `mul` and `pow` expect values in the Montgomery form, as
+/// returned by `to_mont`, and any results may be converted back via
+/// `from_mont`.
+///
+/// ```rust
+/// use i256::{Montgomery, U256};
+///
+/// let ctx = Montgomery::<U256>::new(U256::from_u8(97));
+/// let x = ctx.to_mont(U256::from_u8(5));
+/// let y = ctx.pow(x, U256::from_u8(3));
+/// assert_eq!(ctx.from_mont(y), U256::from_u8(28));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery<T> {
+    pub(crate) modulus: T,
+    pub(crate) r: T,
+    pub(crate) r2: T,
+    pub(crate) neg_inv: ULimb,
+}
+
+#[rustfmt::skip]
+macro_rules! define {
+    (type => $t:ty $(,)?) => {
+        impl $crate::montgomery::Montgomery<$t> {
+            /// Create a new Montgomery context for the odd `modulus`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `modulus` is even.
+            #[inline]
+            #[must_use]
+            pub fn new(modulus: $t) -> Self {
+                match Self::checked_new(modulus) {
+                    Some(ctx) => ctx,
+                    None => panic!("Montgomery modulus must be odd"),
+                }
+            }
+
+            /// Create a new Montgomery context for the `modulus`, returning
+            /// `None` if the modulus is even.
+            #[inline]
+            #[must_use]
+            pub fn checked_new(modulus: $t) -> Option<Self> {
+                if modulus.is_even() {
+                    return None;
+                }
+
+                // NOTE: `R mod N == (R - 1) mod N + 1`, reduced once more.
+                let one = <$t>::from_u8(1);
+                let r = <$t>::MAX.wrapping_rem(modulus).add_mod(one, modulus);
+                let r2 = r.mul_mod(r, modulus);
+                let neg_inv = $crate::math::mont::neg_inv(modulus.least_significant_limb());
+                Some(Self {
+                    modulus,
+                    r,
+                    r2,
+                    neg_inv,
+                })
+            }
+
+            /// Get the modulus of the context.
+            #[inline(always)]
+            pub const fn modulus(&self) -> $t {
+                self.modulus
+            }
+
+            /// Get `1` in the Montgomery form, or `R mod N`.
+            #[inline(always)]
+            pub const fn one(&self) -> $t {
+                self.r
+            }
+
+            /// Convert the value into the Montgomery form, or `x * R mod N`.
+            ///
+            /// The value is first reduced if it's not less than the modulus.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub fn to_mont(&self, x: $t) -> $t {
+                let x = match x.lt_const(self.modulus) {
+                    true => x,
+                    false => x.wrapping_rem(self.modulus),
+                };
+                self.mul(x, self.r2)
+            }
+
+            /// Convert the value from the Montgomery form, or `x * R^-1 mod N`.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn from_mont(&self, x: $t) -> $t {
+                self.mul(x, <$t>::from_u8(1))
+            }
+
+            /// Montgomery multiplication, or `x * y * R^-1 mod N`.
+            ///
+            /// If both values are in the Montgomery form, this produces their
+            /// modular product in the Montgomery form. Both values must be
+            /// less than the modulus, otherwise the result is unspecified.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn mul(&self, x: $t, y: $t) -> $t {
+                let x = x.to_le_limbs();
+                let y = y.to_le_limbs();
+                let n = self.modulus.to_le_limbs();
+                <$t>::from_le_limbs($crate::math::mont::mul(&x, &y, &n, self.neg_inv))
+            }
+
+            /// Montgomery squaring, or `x * x * R^-1 mod N`.
+            ///
+            /// The value must be less than the modulus, otherwise the result
+            /// is unspecified.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn square(&self, x: $t) -> $t {
+                self.mul(x, x)
+            }
+
+            /// Montgomery exponentiation, or `x.pow(exp) * R^(1 - exp) mod N`.
+            ///
+            /// If `x` is in the Montgomery form, this produces the modular
+            /// power in the Montgomery form. This uses fixed-window
+            /// exponentiation, processing 4 bits of the exponent for each
+            /// multiplication. The value must be less than the modulus,
+            /// otherwise the result is unspecified.
+            #[inline]
+            #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+            pub const fn pow(&self, x: $t, exp: $t) -> $t {
+                const WINDOW: u32 = 4;
+
+                // precompute `x^0..x^(2^WINDOW - 1)`
+                let mut table = [self.r; 1 << WINDOW];
+                let mut i = 1;
+                while i < table.len() {
+                    table[i] = self.mul(table[i - 1], x);
+                    i += 1;
+                }
+
+                // process each window from the most-significant bits, skipping
+                // any leading zero windows.
+                let bits = <$t>::BITS - exp.leading_zeros();
+                let mut shift = (bits + WINDOW - 1) / WINDOW * WINDOW;
+                let mut result = self.r;
+                let mut started = false;
+                while shift > 0 {
+                    shift -= WINDOW;
+                    if started {
+                        let mut j = 0;
+                        while j < WINDOW {
+                            result = self.square(result);
+                            j += 1;
+                        }
+                    }
+                    let index = exp.wrapping_shr(shift).least_significant_limb() as usize & ((1 << WINDOW) - 1);
+                    if index != 0 {
+                        result = self.mul(result, table[index]);
+                        started = true;
+                    }
+                }
+
+                result
+            }
+        }
+    };
+}
+
+pub(crate) use define;
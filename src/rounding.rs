@@ -0,0 +1,124 @@
+//! Rounding modes for integer division.
+
+use core::cmp::Ordering;
+
+/// The direction to round an inexact quotient.
+///
+/// `Down` and `Up` round towards negative and positive infinity, which
+/// differ from `TowardZero` and `AwayFromZero` for negative quotients.
+/// The `Half*` modes round to the nearest integer, and only differ in
+/// how ties are broken.
+///
+/// ```rust
+/// use i256::{I256, RoundingMode};
+///
+/// let x = I256::from_i8(-5);
+/// let y = I256::from_u8(2);
+/// assert_eq!(x.div_round(y, RoundingMode::Down), I256::from_i8(-3));
+/// assert_eq!(x.div_round(y, RoundingMode::Up), I256::from_i8(-2));
+/// assert_eq!(x.div_round(y, RoundingMode::HalfEven), I256::from_i8(-2));
+/// assert_eq!(x.div_round(y, RoundingMode::HalfUp), I256::from_i8(-2));
+/// assert_eq!(x.div_round(y, RoundingMode::HalfDown), I256::from_i8(-3));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity, or the floor of the quotient.
+    Down,
+
+    /// Round towards positive infinity, or the ceiling of the quotient.
+    Up,
+
+    /// Round towards zero, or truncate the quotient.
+    TowardZero,
+
+    /// Round away from zero.
+    AwayFromZero,
+
+    /// Round to the nearest integer, with ties rounding to the even
+    /// integer. This is also known as banker's rounding.
+    HalfEven,
+
+    /// Round to the nearest integer, with ties rounding towards positive
+    /// infinity.
+    HalfUp,
+
+    /// Round to the nearest integer, with ties rounding towards negative
+    /// infinity.
+    HalfDown,
+}
+
+impl RoundingMode {
+    /// Get the mode which rounds in the opposite direction, so rounding
+    /// `-x` with the reversed mode gives the negation of rounding `x`.
+    #[inline(always)]
+    pub(crate) const fn reverse(self) -> Self {
+        match self {
+            Self::Down => Self::Up,
+            Self::Up => Self::Down,
+            Self::HalfUp => Self::HalfDown,
+            Self::HalfDown => Self::HalfUp,
+            mode => mode,
+        }
+    }
+
+    /// Determine if an inexact quotient, truncated towards zero, should
+    /// be rounded away from zero.
+    ///
+    /// `half` is the ordering of the magnitude of the remainder to the
+    /// distance from the remainder to the divisor, so it is `Greater` if
+    /// the exact quotient is more than halfway to the next integer.
+    #[inline(always)]
+    pub(crate) const fn is_away(self, is_negative: bool, half: Ordering, is_odd: bool) -> bool {
+        match (self, half) {
+            (Self::Down, _) => is_negative,
+            (Self::Up, _) => !is_negative,
+            (Self::TowardZero, _) => false,
+            (Self::AwayFromZero, _) => true,
+            (_, Ordering::Less) => false,
+            (_, Ordering::Greater) => true,
+            (Self::HalfEven, Ordering::Equal) => is_odd,
+            (Self::HalfUp, Ordering::Equal) => !is_negative,
+            (Self::HalfDown, Ordering::Equal) => is_negative,
+        }
+    }
+}
+
+/// Reference implementations for testing the rounding division.
+#[cfg(test)]
+pub(crate) mod reference {
+    use super::RoundingMode;
+
+    /// All rounding modes.
+    pub(crate) const MODES: [RoundingMode; 7] = [
+        RoundingMode::Down,
+        RoundingMode::Up,
+        RoundingMode::TowardZero,
+        RoundingMode::AwayFromZero,
+        RoundingMode::HalfEven,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+    ];
+
+    /// Divide `x` by `y`, rounding the quotient with `mode`, using
+    /// native integers.
+    pub(crate) fn div_round(x: i64, y: i64, mode: RoundingMode) -> i64 {
+        let floor = x.div_euclid(y) - (y < 0 && x.rem_euclid(y) != 0) as i64;
+        let twice = 2 * (x - floor * y).abs();
+        let half = twice.cmp(&y.abs());
+        let is_exact = floor * y == x;
+        let ceil = floor + !is_exact as i64;
+        match (mode, half) {
+            (RoundingMode::Down, _) => floor,
+            (RoundingMode::Up, _) => ceil,
+            (RoundingMode::TowardZero, _) => x / y,
+            (RoundingMode::AwayFromZero, _) if x / y == floor => ceil,
+            (RoundingMode::AwayFromZero, _) => floor,
+            (_, core::cmp::Ordering::Less) => floor,
+            (_, core::cmp::Ordering::Greater) => ceil,
+            (RoundingMode::HalfEven, _) if floor % 2 == 0 => floor,
+            (RoundingMode::HalfEven, _) => ceil,
+            (RoundingMode::HalfUp, _) => ceil,
+            (RoundingMode::HalfDown, _) => floor,
+        }
+    }
+}
@@ -62,6 +62,17 @@ macro_rules! define {
             }
         }
 
+        /// Checked squaring. Computes `self * self`, returning `None`
+        /// if overflow occurred.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn checked_square(self) -> Option<Self> {
+            match self.overflowing_square() {
+                (value, false) => Some(value),
+                _ => None,
+            }
+        }
+
         /// Checked exponentiation. Computes `self.pow(exp)`, returning `None`
         /// if overflow occurred.
         ///
@@ -179,6 +179,36 @@ macro_rules! define {
         pub fn rem_uwide(self, n: $crate::UWide) -> $crate::UWide {
             self.div_rem_uwide(n).1
         }
+
+        /// Get the quotient of our big integer divided by [`ULimb`][crate::ULimb],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_ulimb(self, n: $crate::ULimb, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_ulimb(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`UWide`][crate::UWide],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_uwide(self, n: $crate::UWide, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_uwide(n), mode)
+        }
     };
 
     (fixed) => {
@@ -467,6 +497,54 @@ macro_rules! define {
         pub fn rem_u128(self, n: u128) -> u128 {
             self.div_rem_u128(n).1
         }
+
+        /// Get the quotient of our big integer divided by [`u32`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_u32(self, n: u32, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_u32(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`u64`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_u64(self, n: u64, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_u64(n), mode)
+        }
+
+        /// Get the quotient of our big integer divided by [`u128`],
+        /// rounding the result with `mode`.
+        ///
+        /// This is the same as [`div_round`][Self::div_round] with the
+        /// divisor converted to our big integer.
+        ///
+        /// # Panics
+        ///
+        /// This panics if the divisor is 0.
+        #[cfg_attr(docsrs, doc(cfg(feature = "stdint")))]
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round_u128(self, n: u128, mode: $crate::RoundingMode) -> Self {
+            self.div_round(Self::from_u128(n), mode)
+        }
     };
 
     (@wrapping) => {
@@ -25,6 +25,28 @@ macro_rules! define {
             }
         }
 
+        /// Squares self, computing `self * self`.
+        ///
+        /// This requires about half the limb multiplications of a general
+        /// multiplication.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if the result overflows and overflow
+        /// checks are enabled.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn square(self) -> Self {
+            if cfg!(not(have_overflow_checks)) {
+                self.wrapping_square()
+            } else {
+                match self.checked_square() {
+                    Some(v) => v,
+                    None => core::panic!("attempt to multiply with overflow"),
+                }
+            }
+        }
+
         /// Get the quotient and remainder of our big integer division.
         ///
         /// This allows storing of both the quotient and remainder without
@@ -56,7 +78,11 @@ macro_rules! traits {
             #[inline(always)]
             fn add(self, rhs: Self) -> Self::Output {
                 if cfg!(not(have_overflow_checks)) {
-                    self.wrapping_add(rhs)
+                    // NOTE: The wrapping sum is the same for signed and
+                    // unsigned integers, and this can use runtime kernels.
+                    let lhs = self.to_ne_limbs();
+                    let rhs = rhs.to_ne_limbs();
+                    Self::from_ne_limbs($crate::math::add::wrapping_runtime(&lhs, &rhs))
                 } else {
                     match self.checked_add(rhs) {
                         Some(v) => v,
@@ -88,7 +114,11 @@ macro_rules! traits {
             #[inline(always)]
             fn mul(self, rhs: Self) -> Self::Output {
                 if cfg!(not(have_overflow_checks)) {
-                    self.wrapping_mul(rhs)
+                    // NOTE: The wrapping product is the same for signed and
+                    // unsigned integers, and this can use runtime kernels.
+                    let lhs = self.to_ne_limbs();
+                    let rhs = rhs.to_ne_limbs();
+                    Self::from_ne_limbs($crate::math::mul::wrapping_runtime(&lhs, &rhs))
                 } else {
                     match self.checked_mul(rhs) {
                         Some(v) => v,
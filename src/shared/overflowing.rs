@@ -38,7 +38,7 @@ macro_rules! define {
                     overflowed |= r.1;
                 }
                 exp /= 2;
-                r = base.overflowing_mul(base);
+                r = base.overflowing_square();
                 base = r.0;
                 overflowed |= r.1;
                 debug_assert!(exp != 0, "logic error in exponentiation, will infinitely loop");
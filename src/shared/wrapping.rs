@@ -30,7 +30,7 @@ macro_rules! define {
                     }
                 }
                 exp /= 2;
-                base = base.wrapping_mul(base);
+                base = base.wrapping_square();
                 debug_assert!(exp != 0, "logic error in exponentiation, will infinitely loop");
             }
         }
@@ -83,6 +83,177 @@ macro_rules! define {
             Some(n)
         }
 
+        /// Returns the `n`th root of the number, rounded down.
+        ///
+        /// Returns `None` if `n` is zero.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_iroot(self, n: u32) -> Option<Self> {
+            let bits = Self::BITS - self.leading_zeros();
+            if n == 0 {
+                return None;
+            } else if n == 1 {
+                return Some(self);
+            } else if n == 2 {
+                return Some(self.isqrt());
+            } else if bits <= u64::BITS {
+                return Some(Self::from_u64($crate::math::root::iroot_u64(self.as_u64(), n)));
+            } else if n >= bits {
+                return Some(Self::from_u8(1));
+            }
+
+            // Seed with `(iroot(m) + 1) * 2^s`, where `m` is the high 64 bits of
+            // the value shifted by `n * s`. Since `self < (m + 1) * 2^(n * s)`,
+            // this is always larger than the root.
+            let shift = (bits - u64::BITS + n - 1) / n;
+            let high = match n * shift < Self::BITS {
+                true => self.wrapping_shr(n * shift).as_u64(),
+                false => 0,
+            };
+            let seed = $crate::math::root::iroot_u64(high, n) + 1;
+            let mut x = Self::from_u64(seed).wrapping_shl(shift);
+            let degree = n as $crate::ULimb;
+            loop {
+                // NOTE: If `x^(n - 1)` overflows, then `self / x^(n - 1)` is 0.
+                // Since `x > root`, `self / x^(n - 1) < x`, so this cannot overflow.
+                let quotient = match x.checked_pow(n - 1) {
+                    Some(power) => self.wrapping_div(power),
+                    None => Self::from_u8(0),
+                };
+                let y = x.wrapping_mul_ulimb(degree - 1).wrapping_add(quotient);
+                let y = y.wrapping_div_ulimb(degree);
+                if y.ge_const(x) {
+                    return Some(x);
+                }
+                x = y;
+            }
+        }
+
+        /// Computes the least common multiple of `self` and `rhs`, returning
+        /// `None` if overflow occurred.
+        ///
+        /// The LCM of `0` and `x` is `0`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_lcm(self, rhs: Self) -> Option<Self> {
+            let gcd = self.gcd(rhs);
+            if gcd.eq_const(Self::from_u8(0)) {
+                return Some(gcd);
+            }
+            self.wrapping_div(gcd).checked_mul(rhs)
+        }
+
+        /// Calculates `self * b / c`, rounded down, returning `None` if `c`
+        /// is zero or the quotient overflows.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_mul_div(self, b: Self, c: Self) -> Option<Self> {
+            match self.checked_mul_div_rem(b, c) {
+                Some((quo, _)) => Some(quo),
+                None => None,
+            }
+        }
+
+        /// Calculates the quotient and remainder of `self * b / c`, returning
+        /// `None` if `c` is zero or the quotient overflows.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn checked_mul_div_rem(self, b: Self, c: Self) -> Option<(Self, Self)> {
+            if c.eq_const(Self::from_u8(0)) {
+                return None;
+            }
+            let (lo, hi) = $crate::math::mul::widening_runtime(&self.to_ne_limbs(), &b.to_ne_limbs());
+            let (lo, hi) = (Self::from_ne_limbs(lo), Self::from_ne_limbs(hi));
+            // NOTE: The quotient fits in a single width if and only if `hi < c`.
+            if hi.ge_const(c) {
+                return None;
+            }
+            let (quo, _, rem) = Self::wide_div_rem(lo, hi, c);
+            Some((quo, rem))
+        }
+
+        /// Computes the factorial `n!`, returning `None` if overflow occurred.
+        #[inline]
+        #[must_use]
+        pub fn checked_factorial(n: u32) -> Option<Self> {
+            Self::checked_falling_factorial(n, n)
+        }
+
+        /// Computes the falling factorial `n * (n - 1) * ... * (n - k + 1)`,
+        /// returning `None` if overflow occurred.
+        ///
+        /// This is the number of ordered selections of `k` items from `n`,
+        /// so it is `0` if `k > n`, and `1` if `k == 0`.
+        #[inline]
+        #[must_use]
+        pub fn checked_falling_factorial(n: u32, k: u32) -> Option<Self> {
+            if k > n {
+                return Some(Self::from_u8(0));
+            }
+
+            // NOTE: Multiplying by the smallest factors first means the
+            // product only overflows if the final result does.
+            let mut result = Self::from_u8(1);
+            let mut i = n - k;
+            while i < n {
+                i += 1;
+                let (product, overflowed) = result.overflowing_mul_ulimb(i as $crate::ULimb);
+                if overflowed {
+                    return None;
+                }
+                result = product;
+            }
+            Some(result)
+        }
+
+        /// Computes the binomial coefficient `C(n, k)`, returning `None` if
+        /// overflow occurred.
+        ///
+        /// This is the number of unordered selections of `k` items from `n`,
+        /// so it is `0` if `k > n`. Each intermediate value is a smaller
+        /// binomial coefficient, so this only overflows if the result does.
+        #[inline]
+        #[must_use]
+        pub fn checked_binomial(n: u32, k: u32) -> Option<Self> {
+            if k > n {
+                return Some(Self::from_u8(0));
+            }
+
+            // NOTE: After each step, `result == C(n - k + i, i)`, and
+            // `C(n - k + i, i) == C(n - k + i - 1, i - 1) * (n - k + i) / i`.
+            // Dividing out `g = gcd(result, i)` first means `i / g` must
+            // divide `n - k + i`, so the product is exact and never exceeds
+            // the final result.
+            let k = if k > n - k { n - k } else { k };
+            let mut result = Self::from_u8(1);
+            let mut i = 0;
+            while i < k {
+                i += 1;
+                let (_, rem) = result.wrapping_div_rem_ulimb(i as $crate::ULimb);
+                let mut g = i;
+                let mut r = rem as u32;
+                while r != 0 {
+                    let t = g % r;
+                    g = r;
+                    r = t;
+                }
+                let (quotient, _) = result.wrapping_div_rem_ulimb(g as $crate::ULimb);
+                let factor = (n - k + i) / (i / g);
+                let (product, overflowed) = quotient.overflowing_mul_ulimb(factor as $crate::ULimb);
+                if overflowed {
+                    return None;
+                }
+                result = product;
+            }
+            Some(result)
+        }
+
         // FIXME: Stabilize when our MSRV goes to `1.67.0+`.
         // /// Returns the base 10 logarithm of the number, rounded down.
         // ///
@@ -0,0 +1,189 @@
+//! Conversions between unsigned big integers and floating-point types.
+//!
+//! Conversions to floats round to the nearest representable value, with
+//! ties to even, identical to an `as` cast from a primitive integer.
+//! Conversions from floats always truncate the fractional component, and
+//! then either fail, saturate or wrap if the integral value is out of range.
+
+#[rustfmt::skip]
+macro_rules! define {
+    () => {
+        /// Create the integer from the magnitude `significand * 2^exp`,
+        /// truncating any fractional bits.
+        ///
+        /// Returns the wrapped value along with a boolean indicating if
+        /// the integral value could not be represented by the type.
+        #[inline]
+        pub(crate) const fn overflowing_from_float_parts(exp: i32, significand: u64) -> (Self, bool) {
+            if significand == 0 {
+                (Self::from_u8(0), false)
+            } else if exp < 0 {
+                let shift = exp.unsigned_abs();
+                if shift >= u64::BITS {
+                    (Self::from_u8(0), false)
+                } else {
+                    (Self::from_u64(significand >> shift), false)
+                }
+            } else {
+                let shift = exp as u32;
+                let bits = u64::BITS - significand.leading_zeros() + shift;
+                let value = if shift >= Self::BITS {
+                    Self::from_u8(0)
+                } else {
+                    Self::from_u64(significand).wrapping_shl(shift)
+                };
+                (value, bits > Self::BITS)
+            }
+        }
+
+        /// Converts the integer to the nearest [`f64`], with ties rounding
+        /// to even.
+        ///
+        /// Values too large to be represented are converted to infinity,
+        /// identical to an `as` cast from a primitive integer.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn to_f64(self) -> f64 {
+            let bits = Self::BITS - self.leading_zeros();
+            if bits <= u128::BITS {
+                return self.as_u128() as f64;
+            }
+
+            // Keep the high 64 bits and fold any truncated bits into the
+            // lowest, "sticky" bit. Since this has well over the 2 guard bits
+            // required, rounding the sticky value produces the same result as
+            // rounding the exact value. Scaling by a power-of-two is exact,
+            // except on overflow, which correctly rounds to infinity.
+            let shift = bits - u64::BITS;
+            let truncated = self.trailing_zeros() < shift;
+            let high = self.wrapping_shr(shift).as_u64() | truncated as u64;
+            high as f64 * $crate::util::f64_pow2(shift)
+        }
+
+        /// Converts the integer to the nearest [`f32`], with ties rounding
+        /// to even.
+        ///
+        /// Values too large to be represented are converted to infinity,
+        /// identical to an `as` cast from a primitive integer.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn to_f32(self) -> f32 {
+            // NOTE: Any value `>= 2^128` is larger than `f32::MAX` rounded
+            // up by half an ULP, so it must round to infinity.
+            let bits = Self::BITS - self.leading_zeros();
+            if bits <= u128::BITS {
+                self.as_u128() as f32
+            } else {
+                f32::INFINITY
+            }
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component.
+        ///
+        /// Returns `None` if the value is NaN, infinite, or if the truncated
+        /// value is negative or too large to be represented by the type.
+        #[inline]
+        pub fn checked_from_f64(value: f64) -> Option<Self> {
+            if !value.is_finite() {
+                return None;
+            }
+            let (negative, exp, significand) = $crate::util::f64_to_parts(value);
+            let (result, overflowed) = Self::overflowing_from_float_parts(exp, significand);
+            if overflowed || (negative && !result.eq_const(Self::from_u8(0))) {
+                None
+            } else {
+                Some(result)
+            }
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component.
+        ///
+        /// Returns `None` if the value is NaN, infinite, or if the truncated
+        /// value is negative or too large to be represented by the type.
+        #[inline]
+        pub fn checked_from_f32(value: f32) -> Option<Self> {
+            if !value.is_finite() {
+                return None;
+            }
+            let (negative, exp, significand) = $crate::util::f32_to_parts(value);
+            let (result, overflowed) = Self::overflowing_from_float_parts(exp, significand);
+            if overflowed || (negative && !result.eq_const(Self::from_u8(0))) {
+                None
+            } else {
+                Some(result)
+            }
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component and saturating at the numeric bounds.
+        ///
+        /// NaN is converted to `0`, identical to an `as` cast to a
+        /// primitive integer.
+        #[inline]
+        pub fn saturating_from_f64(value: f64) -> Self {
+            if value.is_nan() || value.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::checked_from_f64(value).unwrap_or(Self::MAX)
+            }
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component and saturating at the numeric bounds.
+        ///
+        /// NaN is converted to `0`, identical to an `as` cast to a
+        /// primitive integer.
+        #[inline]
+        pub fn saturating_from_f32(value: f32) -> Self {
+            if value.is_nan() || value.is_sign_negative() {
+                Self::MIN
+            } else {
+                Self::checked_from_f32(value).unwrap_or(Self::MAX)
+            }
+        }
+
+        /// Converts a [`f64`] to the integer, truncating the fractional
+        /// component and wrapping around at the boundary of the type.
+        ///
+        /// This keeps only the low bits of the integral value, as if it
+        /// had been calculated with infinite precision, and then truncated
+        /// to the type. NaN and infinite values are converted to `0`.
+        #[inline]
+        pub fn wrapping_from_f64(value: f64) -> Self {
+            if !value.is_finite() {
+                return Self::from_u8(0);
+            }
+            let (negative, exp, significand) = $crate::util::f64_to_parts(value);
+            let result = Self::overflowing_from_float_parts(exp, significand).0;
+            if negative {
+                result.wrapping_neg()
+            } else {
+                result
+            }
+        }
+
+        /// Converts a [`f32`] to the integer, truncating the fractional
+        /// component and wrapping around at the boundary of the type.
+        ///
+        /// This keeps only the low bits of the integral value, as if it
+        /// had been calculated with infinite precision, and then truncated
+        /// to the type. NaN and infinite values are converted to `0`.
+        #[inline]
+        pub fn wrapping_from_f32(value: f32) -> Self {
+            if !value.is_finite() {
+                return Self::from_u8(0);
+            }
+            let (negative, exp, significand) = $crate::util::f32_to_parts(value);
+            let result = Self::overflowing_from_float_parts(exp, significand).0;
+            if negative {
+                result.wrapping_neg()
+            } else {
+                result
+            }
+        }
+    };
+}
+
+pub(crate) use define;
@@ -19,9 +19,12 @@ pub(crate) mod casts;
 pub(crate) mod checked;
 pub(crate) mod constants;
 pub(crate) mod extensions;
+pub(crate) mod float;
 pub(crate) mod limb;
+pub(crate) mod modular;
 pub(crate) mod ops;
 pub(crate) mod overflowing;
+pub(crate) mod prime;
 pub(crate) mod saturating;
 pub(crate) mod strict;
 pub(crate) mod traits;
@@ -78,6 +81,7 @@ macro_rules! define {
                 see_type => u64,
                 kind => unsigned,
             );
+            $crate::uint::float::define!();
             $crate::uint::extensions::define!(
                 high_type => $crate::ULimb,
             );
@@ -125,12 +129,17 @@ macro_rules! define {
                 wide_type => $crate::UWide,
             );
             $crate::uint::limb::define!(@all);
+            $crate::uint::modular::define!();
+            $crate::uint::prime::define!(bits => $bits);
 
             $crate::parse::define!(false);
             $crate::write::define!(false);
         }
 
         $crate::uint::traits::define!(type => $name, signed_type => $s_t);
+        $crate::montgomery::define!(type => $name);
+        $crate::barrett::define!(type => $name);
+        $crate::divider::define!(type => $name);
     };
 }
 
@@ -0,0 +1,487 @@
+//! Modular arithmetic operations for unsigned integers.
+//!
+//! All operations accept operands of any size, which are reduced by the
+//! modulus as required, and never overflow before the final reduction.
+
+#[rustfmt::skip]
+macro_rules! define {
+    () => {
+        /// Calculates the quotient and remainder of the double-width value
+        /// `(lo, hi)` divided by `d`, where `lo` has the low bits and `hi`
+        /// the high bits of the numerator, as returned by [`widening_mul`].
+        ///
+        /// Returns the low and high bits of the quotient, and the remainder.
+        ///
+        /// [`widening_mul`]: Self::widening_mul
+        #[inline]
+        pub(crate) fn wide_div_rem(lo: Self, hi: Self, d: Self) -> (Self, Self, Self) {
+            if hi.eq_const(Self::from_u8(0)) {
+                let (quo, rem) = lo.wrapping_div_rem(d);
+                return (quo, hi, rem);
+            }
+
+            // NOTE: Our algorithm assumes little-endian order, which we might not have.
+            let lo = lo.to_le_limbs();
+            let hi = hi.to_le_limbs();
+            let mut x = [0; Self::LIMBS * 2];
+            let mut i = 0;
+            while i < Self::LIMBS {
+                x[i] = lo[i];
+                x[i + Self::LIMBS] = hi[i];
+                i += 1;
+            }
+            let y = d.to_le_limbs();
+
+            let (quo, rem) = $crate::math::div::full(&x, &y);
+            let mut quo_lo = [0; Self::LIMBS];
+            let mut quo_hi = [0; Self::LIMBS];
+            i = 0;
+            while i < Self::LIMBS {
+                quo_lo[i] = quo[i];
+                quo_hi[i] = quo[i + Self::LIMBS];
+                i += 1;
+            }
+            (Self::from_le_limbs(quo_lo), Self::from_le_limbs(quo_hi), Self::from_le_limbs(rem))
+        }
+
+        /// Calculates the remainder of the double-width value `(lo, hi)`
+        /// divided by `m`, where `lo` has the low bits and `hi` the high
+        /// bits of the numerator, as returned by [`widening_mul`].
+        ///
+        /// [`widening_mul`]: Self::widening_mul
+        #[inline]
+        pub(crate) fn wide_rem(lo: Self, hi: Self, m: Self) -> Self {
+            Self::wide_div_rem(lo, hi, m).2
+        }
+
+        /// Reduce the value modulo `m`, if required.
+        #[inline(always)]
+        fn reduce_mod(self, m: Self) -> Self {
+            match self.lt_const(m) {
+                true => self,
+                false => self.wrapping_rem(m),
+            }
+        }
+
+        /// Modular addition. Computes `(self + rhs) mod m`.
+        ///
+        /// The sum is never truncated prior to the reduction.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!(m)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn add_mod(self, rhs: Self, m: Self) -> Self {
+            assert!(!m.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+            let x = self.reduce_mod(m);
+            let y = rhs.reduce_mod(m);
+            // NOTE: Since `x, y < m`, `x + y < 2m`, so a single subtraction
+            // of the modulus is enough, and wrapping handles the carry.
+            let (sum, overflowed) = $crate::math::add::overflowing_runtime(&x.to_ne_limbs(), &y.to_ne_limbs());
+            let sum = Self::from_ne_limbs(sum);
+            if overflowed || sum.ge_const(m) {
+                sum.wrapping_sub(m)
+            } else {
+                sum
+            }
+        }
+
+        /// Modular subtraction. Computes `(self - rhs) mod m`.
+        ///
+        /// The result is always in the range `[0, m)`, even if `rhs > self`.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!(m)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn sub_mod(self, rhs: Self, m: Self) -> Self {
+            assert!(!m.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+            let x = self.reduce_mod(m);
+            let y = rhs.reduce_mod(m);
+            match x.overflowing_sub(y) {
+                (diff, true) => diff.wrapping_add(m),
+                (diff, false) => diff,
+            }
+        }
+
+        /// Modular multiplication. Computes `(self * rhs) mod m`.
+        ///
+        /// This calculates the full, double-width product prior to the
+        /// reduction, so it never overflows.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!(m)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_mod(self, rhs: Self, m: Self) -> Self {
+            assert!(!m.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+            let (lo, hi) = $crate::math::mul::widening_runtime(&self.to_ne_limbs(), &rhs.to_ne_limbs());
+            Self::wide_rem(Self::from_ne_limbs(lo), Self::from_ne_limbs(hi), m)
+        }
+
+        /// Modular exponentiation. Computes `self.pow(exp) mod m`.
+        ///
+        /// This uses fixed-window exponentiation, processing 4 bits of the
+        /// exponent for each multiplication, after 4 squarings. For odd
+        /// moduli, this uses Montgomery multiplication, avoiding any
+        /// division after the initial setup.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!(m)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn pow_mod(self, exp: Self, m: Self) -> Self {
+            const WINDOW: u32 = 4;
+            assert!(!m.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+
+            if m.is_odd() {
+                let ctx = $crate::Montgomery::<Self>::new(m);
+                return ctx.from_mont(ctx.pow(ctx.to_mont(self), exp));
+            }
+
+            let one = Self::from_u8(1).reduce_mod(m);
+            if exp.eq_const(Self::from_u8(0)) {
+                return one;
+            }
+
+            // precompute `self^0..self^(2^WINDOW - 1)`
+            let base = self.reduce_mod(m);
+            let mut table = [one; 1 << WINDOW];
+            let mut i = 1;
+            while i < table.len() {
+                table[i] = table[i - 1].mul_mod(base, m);
+                i += 1;
+            }
+
+            // process each window from the most-significant bits, skipping
+            // any leading zero windows.
+            let bits = Self::BITS - exp.leading_zeros();
+            let mut shift = (bits + WINDOW - 1) / WINDOW * WINDOW;
+            let mut result = one;
+            let mut started = false;
+            while shift > 0 {
+                shift -= WINDOW;
+                if started {
+                    let mut j = 0;
+                    while j < WINDOW {
+                        result = result.mul_mod(result, m);
+                        j += 1;
+                    }
+                }
+                let index = exp.wrapping_shr(shift).as_u32() & ((1 << WINDOW) - 1);
+                if index != 0 {
+                    result = result.mul_mod(table[index as usize], m);
+                    started = true;
+                }
+            }
+
+            result
+        }
+
+        /// Modular multiplicative inverse. Computes `x` such that
+        /// `(self * x) mod m == 1 mod m`.
+        ///
+        /// Returns `None` if `self` and `m` are not coprime, and therefore
+        /// the inverse does not exist.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!(m)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn inv_mod(self, m: Self) -> Option<Self> {
+            assert!(!m.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+
+            // NOTE: This is the extended Euclidean algorithm, only tracking
+            // the coefficients of `self`. The coefficients alternate in sign
+            // and their magnitudes are bounded by `m`, so we can store their
+            // magnitudes and use `|t[i+1]| = |t[i-1]| + q * |t[i]|`.
+            let zero = Self::from_u8(0);
+            let mut r0 = m;
+            let mut r1 = self.reduce_mod(m);
+            let mut t0 = zero;
+            let mut t1 = Self::from_u8(1);
+            let mut t1_negative = false;
+            while !r1.eq_const(zero) {
+                let (q, r) = r0.wrapping_div_rem(r1);
+                let t = t0.wrapping_add(q.wrapping_mul(t1));
+                r0 = r1;
+                r1 = r;
+                t0 = t1;
+                t1 = t;
+                t1_negative = !t1_negative;
+            }
+
+            // NOTE: `t0` is the coefficient for `r0`, which has the opposite
+            // sign of `t1`. For `m == 1`, `r0` is the modulus and `t0 == 0`.
+            if m.eq_const(Self::from_u8(1)) {
+                Some(zero)
+            } else if !r0.eq_const(Self::from_u8(1)) {
+                None
+            } else if !t1_negative {
+                Some(m.wrapping_sub(t0))
+            } else {
+                Some(t0)
+            }
+        }
+
+        /// Calculates the Jacobi symbol `(self / n)`.
+        ///
+        /// This is `0` if `self` and `n` are not coprime, and otherwise `1`
+        /// or `-1`. If `n` is prime, this is the Legendre symbol, and is
+        /// `1` if and only if `self` is a quadratic residue modulo `n`.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `n` is even.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn jacobi(self, n: Self) -> i32 {
+            assert!(n.is_odd(), "Jacobi symbol requires an odd denominator");
+
+            // NOTE: This is the binary algorithm, which replaces the division
+            // in each step of the law of quadratic reciprocity with a
+            // subtraction, since `(a / n) == ((a - n) / n)`.
+            let zero = Self::from_u8(0);
+            let mut a = self;
+            let mut n = n;
+            let mut result = 1;
+            while !a.eq_const(zero) {
+                // NOTE: `(2 / n) == -1` if and only if `n == 3 or 5 mod 8`.
+                let zeros = a.trailing_zeros();
+                a = a.wrapping_shr(zeros);
+                let n_mod_8 = n.as_u32() % 8;
+                if zeros % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+                    result = -result;
+                }
+                // NOTE: `(a / n) == -(n / a)` if and only if `a == n == 3 mod 4`.
+                if a.lt_const(n) {
+                    if a.as_u32() % 4 == 3 && n_mod_8 % 4 == 3 {
+                        result = -result;
+                    }
+                    let t = a;
+                    a = n;
+                    n = t;
+                }
+                a = a.wrapping_sub(n);
+            }
+
+            match n.eq_const(Self::from_u8(1)) {
+                true => result,
+                false => 0,
+            }
+        }
+
+        /// Calculates the Legendre symbol `(self / p)` for an odd prime `p`.
+        ///
+        /// This is `0` if `p` divides `self`, `1` if `self` is a quadratic
+        /// residue modulo `p`, and `-1` otherwise. `p` is not checked for
+        /// primality, and this is identical to the [`jacobi`] symbol.
+        ///
+        /// [`jacobi`]: Self::jacobi
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `p` is even.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn legendre(self, p: Self) -> i32 {
+            self.jacobi(p)
+        }
+
+        /// Modular square root. Computes `x` such that `(x * x) mod p == self mod p`,
+        /// for a prime `p`.
+        ///
+        /// Returns `None` if `self` is not a quadratic residue modulo `p`.
+        /// Either root may be returned, and the other is `p - x`. This uses
+        /// a single exponentiation for `p == 3 mod 4`, Atkin's algorithm for
+        /// `p == 5 mod 8`, and otherwise the Tonelli-Shanks algorithm, or
+        /// Cipolla's algorithm if `p - 1` is divisible by a large power of 2,
+        /// where Tonelli-Shanks is slow. `p` is not checked for primality,
+        /// and the result is always a valid root, but may be `None` for a
+        /// composite `p` even if a root exists.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `p` is zero, or is even and not `2`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn sqrt_mod(self, p: Self) -> Option<Self> {
+            assert!(!p.eq_const(Self::from_u8(0)), "attempt to calculate the remainder with a divisor of zero");
+            let one = Self::from_u8(1);
+            let two = Self::from_u8(2);
+            assert!(p.is_odd() || p.eq_const(two), "modular square root requires an odd modulus or 2");
+            let a = self.reduce_mod(p);
+            if p.eq_const(two) || a.lt_const(two) {
+                return Some(a);
+            } else if a.legendre(p) != 1 {
+                return None;
+            }
+
+            let ctx = $crate::Montgomery::<Self>::new(p);
+            let a_m = ctx.to_mont(a);
+            let p_mod_8 = p.as_u32() % 8;
+            let root = if p_mod_8 % 4 == 3 {
+                // `a^((p + 1) / 4)`, since `a^((p - 1) / 2) == 1`.
+                ctx.pow(a_m, p.wrapping_shr(2).wrapping_add(one))
+            } else if p_mod_8 == 5 {
+                // NOTE: This is Atkin's algorithm, where `i = 2a * v^2` is a
+                // square root of `-1`, so `(a * v * (i - 1))^2 == a`.
+                let two_a = a_m.add_mod(a_m, p);
+                let v = ctx.pow(two_a, p.wrapping_shr(3));
+                let i = ctx.mul(two_a, ctx.square(v));
+                ctx.mul(ctx.mul(a_m, v), i.sub_mod(ctx.one(), p))
+            } else {
+                let s = p.wrapping_sub(one).trailing_zeros();
+                let bits = Self::BITS - p.leading_zeros();
+                // NOTE: Tonelli-Shanks requires up to `s^2 / 2` squarings after
+                // the initial exponentiation, while Cipolla's algorithm requires
+                // about 6 multiplications for each bit of `p`.
+                if s * s > 8 * bits {
+                    Self::cipolla(&ctx, a_m)?
+                } else {
+                    Self::tonelli_shanks(&ctx, a_m, s)?
+                }
+            };
+
+            match ctx.square(root).eq_const(a_m) {
+                true => Some(ctx.from_mont(root)),
+                false => None,
+            }
+        }
+
+        /// Find a small value `z` such that `f(z)` is a quadratic non-residue
+        /// modulo the odd modulus `p`.
+        ///
+        /// This returns `None` if none is found after `2 * bits^2` attempts,
+        /// which is always sufficient for a prime `p` if the generalized
+        /// Riemann hypothesis is true.
+        #[inline]
+        fn find_non_residue(p: Self, f: impl Fn(Self) -> Self) -> Option<Self> {
+            let bits = Self::BITS - p.leading_zeros();
+            let mut z = 1;
+            while z <= 2 * bits * bits {
+                let x = Self::from_u32(z);
+                if f(x).legendre(p) == -1 {
+                    return Some(x);
+                }
+                z += 1;
+            }
+            None
+        }
+
+        /// Find the square root of the quadratic residue `a` in Montgomery form
+        /// modulo the prime `p == 1 mod 2^s`, using the Tonelli-Shanks algorithm.
+        #[inline]
+        fn tonelli_shanks(ctx: &$crate::Montgomery<Self>, a: Self, s: u32) -> Option<Self> {
+            let p = ctx.modulus();
+            let q = p.wrapping_shr(s);
+            let z = Self::find_non_residue(p, |z| z)?;
+
+            // NOTE: We maintain `r^2 == a * t`, where `t` has order `2^i` for
+            // some `i < m`, and `c` is a primitive `2^m`th root of unity.
+            let mut m = s;
+            let mut c = ctx.pow(ctx.to_mont(z), q);
+            let mut t = ctx.pow(a, q);
+            let mut r = ctx.pow(a, q.wrapping_shr(1).wrapping_add(Self::from_u8(1)));
+            while !t.eq_const(ctx.one()) {
+                let mut i = 0;
+                let mut t2i = t;
+                while !t2i.eq_const(ctx.one()) {
+                    t2i = ctx.square(t2i);
+                    i += 1;
+                    if i == m {
+                        return None;
+                    }
+                }
+                let mut b = c;
+                let mut j = i + 1;
+                while j < m {
+                    b = ctx.square(b);
+                    j += 1;
+                }
+                m = i;
+                c = ctx.square(b);
+                t = ctx.mul(t, c);
+                r = ctx.mul(r, b);
+            }
+            Some(r)
+        }
+
+        /// Find the square root of the quadratic residue `a` in Montgomery form
+        /// modulo the prime `p`, using Cipolla's algorithm.
+        #[inline]
+        fn cipolla(ctx: &$crate::Montgomery<Self>, a: Self) -> Option<Self> {
+            let p = ctx.modulus();
+            let a_n = ctx.from_mont(a);
+            let x = Self::find_non_residue(p, |x| x.mul_mod(x, p).sub_mod(a_n, p))?;
+
+            // NOTE: This calculates `(x + w)^((p + 1) / 2)` in `F_p^2`, where
+            // `w^2 = x^2 - a` is a non-residue, which is a root of `a` in `F_p`.
+            let x = ctx.to_mont(x);
+            let w2 = ctx.square(x).sub_mod(a, p);
+            let mul = |(x0, y0): (Self, Self), (x1, y1): (Self, Self)| {
+                let x = ctx.mul(x0, x1).add_mod(ctx.mul(ctx.mul(y0, y1), w2), p);
+                let y = ctx.mul(x0, y1).add_mod(ctx.mul(y0, x1), p);
+                (x, y)
+            };
+            let exp = p.wrapping_shr(1).wrapping_add(Self::from_u8(1));
+            let base = (x, ctx.one());
+            let mut result = base;
+            let mut bit = Self::BITS - exp.leading_zeros() - 1;
+            while bit > 0 {
+                bit -= 1;
+                result = mul(result, result);
+                if exp.wrapping_shr(bit).is_odd() {
+                    result = mul(result, base);
+                }
+            }
+            Some(result.0)
+        }
+
+        /// Solves a system of congruences using the Chinese remainder theorem.
+        ///
+        /// This finds the smallest `x` such that `x mod moduli[i] == residues[i] mod moduli[i]`
+        /// for every `i`, which is unique modulo the least common multiple of
+        /// the moduli. The moduli do not need to be pairwise coprime, and an
+        /// empty system has the solution `0`.
+        ///
+        /// Returns `None` if the number of residues and moduli differ, if any
+        /// modulus is zero, if the system has no solution, or if the least
+        /// common multiple of the moduli overflows.
+        #[inline]
+        #[must_use]
+        pub fn crt(residues: &[Self], moduli: &[Self]) -> Option<Self> {
+            if residues.len() != moduli.len() {
+                return None;
+            }
+
+            // NOTE: This combines each congruence with the solution so far,
+            // `x mod m`. For `g = gcd(m, n)`, a solution to `x + m * t == r mod n`
+            // exists only if `g` divides `r - x`, and then
+            // `t == ((r - x) / g) * (m / g)^-1 mod (n / g)`.
+            let zero = Self::from_u8(0);
+            let mut x = zero;
+            let mut m = Self::from_u8(1);
+            let mut i = 0;
+            while i < moduli.len() {
+                let n = moduli[i];
+                if n.eq_const(zero) {
+                    return None;
+                }
+                let g = m.gcd(n);
+                let (diff, rem) = residues[i].sub_mod(x, n).wrapping_div_rem(g);
+                if !rem.eq_const(zero) {
+                    return None;
+                }
+                let n_g = n.wrapping_div(g);
+                let lcm = m.checked_mul(n_g)?;
+                // NOTE: `m / g` and `n / g` are coprime, so this always exists.
+                let inv = m.wrapping_div(g).inv_mod(n_g)?;
+                // NOTE: `t < n / g` and `x < m`, so `x + m * t < lcm`.
+                let t = diff.mul_mod(inv, n_g);
+                x = x.wrapping_add(m.wrapping_mul(t));
+                m = lcm;
+                i += 1;
+            }
+
+            Some(x)
+        }
+    };
+}
+
+pub(crate) use define;
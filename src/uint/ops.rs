@@ -101,6 +101,131 @@ macro_rules! define {
             }
         }
 
+        // If the truncated quotient `quo`, with the non-zero remainder `rem`,
+        // should be rounded up.
+        #[inline(always)]
+        fn is_round_away(quo: Self, rem: Self, rhs: Self, mode: $crate::RoundingMode) -> bool {
+            let half = rem.cmp(&rhs.wrapping_sub(rem));
+            mode.is_away(false, half, quo.least_significant_limb() & 1 != 0)
+        }
+
+        /// Calculates the quotient of `self` and `rhs`, rounding the result
+        /// with `mode`.
+        ///
+        /// Since the quotient is never negative, [`Down`] and [`TowardZero`]
+        /// are the same as [`div_floor`], and [`Up`] and [`AwayFromZero`] are
+        /// the same as [`div_ceil`].
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        /// [`Down`]: crate::RoundingMode::Down
+        /// [`TowardZero`]: crate::RoundingMode::TowardZero
+        /// [`Up`]: crate::RoundingMode::Up
+        /// [`AwayFromZero`]: crate::RoundingMode::AwayFromZero
+        /// [`div_floor`]: Self::div_floor
+        /// [`div_ceil`]: Self::div_ceil
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_round(self, rhs: Self, mode: $crate::RoundingMode) -> Self {
+            self.div_rem_round(rhs, mode).0
+        }
+
+        /// Calculates the quotient and remainder of `self` and `rhs`, rounding
+        /// the quotient with `mode`.
+        ///
+        /// The remainder is `self - quotient * rhs`, with wrapping arithmetic,
+        /// so if the quotient was rounded up, the remainder is the two's
+        /// complement of the distance from `quotient * rhs` to `self`.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn div_rem_round(self, rhs: Self, mode: $crate::RoundingMode) -> (Self, Self) {
+            let (quo, rem) = self.div_rem(rhs);
+            if !rem.eq_const(Self::from_u8(0)) && Self::is_round_away(quo, rem, rhs, mode) {
+                // NOTE: This can't overflow, since `rhs > 1` if the remainder is non-zero.
+                (quo.wrapping_add_ulimb(1), rem.wrapping_sub(rhs))
+            } else {
+                (quo, rem)
+            }
+        }
+
+        /// Rounds `self` to a multiple of `rhs` with `mode`.
+        ///
+        /// This is the same as `self.div_round(rhs, mode) * rhs`, and so the
+        /// [`Half*`] modes round to the nearest multiple.
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        #[doc = $crate::shared::docs::overflow_assertions_doc!()]
+        ///
+        /// [`Half*`]: crate::RoundingMode::HalfEven
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn round_to_multiple_of(self, rhs: Self, mode: $crate::RoundingMode) -> Self {
+            use core::ops::{Add, Sub};
+
+            let (quo, rem) = self.div_rem(rhs);
+            if rem.eq_const(Self::from_u8(0)) {
+                self
+            } else if Self::is_round_away(quo, rem, rhs, mode) {
+                self.add(rhs.sub(rem))
+            } else {
+                self.wrapping_sub(rem)
+            }
+        }
+
+        /// Calculates the quotient and remainder of `self` and `rhs`, which can
+        /// be used in `const` contexts.
+        ///
+        /// This uses binary long division, which is much slower than [`div_rem`]
+        /// at runtime, so it should only be used for constants.
+        ///
+        /// ```rust
+        /// use i256::U256;
+        ///
+        /// const X: U256 = U256::MAX.div_const(U256::from_u64(10u64.pow(18)));
+        /// const Y: U256 = U256::MAX.rem_const(U256::from_u64(10u64.pow(18)));
+        /// assert_eq!((X, Y), U256::MAX.div_rem(U256::from_u64(10u64.pow(18))));
+        /// ```
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        /// [`div_rem`]: Self::div_rem
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn div_rem_const(self, rhs: Self) -> (Self, Self) {
+            // NOTE: Our algorithm assumes little-endian order, which we might not have.
+            let x = self.to_le_limbs();
+            let y = rhs.to_le_limbs();
+            let (div, rem) = $crate::math::div::full_const(&x, &y);
+            (Self::from_le_limbs(div), Self::from_le_limbs(rem))
+        }
+
+        /// Calculates the quotient of `self` and `rhs`, which can be used in
+        /// `const` contexts. See [`div_rem_const`].
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        /// [`div_rem_const`]: Self::div_rem_const
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn div_const(self, rhs: Self) -> Self {
+            self.div_rem_const(rhs).0
+        }
+
+        /// Calculates the remainder of `self` and `rhs`, which can be used in
+        /// `const` contexts. See [`div_rem_const`].
+        ///
+        #[doc = $crate::shared::docs::div_by_zero_doc!()]
+        ///
+        /// [`div_rem_const`]: Self::div_rem_const
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn rem_const(self, rhs: Self) -> Self {
+            self.div_rem_const(rhs).1
+        }
+
         /// Returns the logarithm of the number with respect to an arbitrary base,
         /// rounded down.
         ///
@@ -151,12 +276,236 @@ macro_rules! define {
         //     }
         // }
 
-        // FIXME: Stabilize when our MSRV goes to `1.84.0+`.
-        // /// Returns the square root of the number, rounded down.
-        // #[inline]
-        // pub const fn isqrt(self) -> Self {
-        //     todo!();
-        // }
+        /// Returns the square root of the number, rounded down.
+        ///
+        /// This is seeded from the high 64 bits of the value, and then
+        /// refined using Newton's method, which converges quadratically
+        /// from above to the root. Convergence is detected by squaring
+        /// each iterate, which is cheaper than another division.
+        ///
+        #[doc = $crate::shared::docs::primitive_doc!($see_t, isqrt)]
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn isqrt(self) -> Self {
+            let (mut x, is_root) = self.isqrt_seed();
+            if is_root {
+                return x;
+            }
+            loop {
+                // NOTE: Since `x > sqrt(self)`, `self / x < x`, so this cannot overflow.
+                let y = x.wrapping_add(self.wrapping_div(x)).wrapping_shr(1);
+                if self.is_isqrt_iterate(y) {
+                    return y;
+                }
+                x = y;
+            }
+        }
+
+        /// Returns the square root of the number, rounded down, which can be
+        /// used in `const` contexts.
+        ///
+        /// This uses [`div_const`] for each Newton iteration, which is much
+        /// slower than [`isqrt`] at runtime, so it should only be used for
+        /// constants.
+        ///
+        /// ```rust
+        /// use i256::U256;
+        ///
+        /// const ROOT: U256 = U256::MAX.isqrt_const();
+        /// assert_eq!(ROOT, U256::MAX.isqrt());
+        /// ```
+        ///
+        /// [`div_const`]: Self::div_const
+        /// [`isqrt`]: Self::isqrt
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn isqrt_const(self) -> Self {
+            let (mut x, is_root) = self.isqrt_seed();
+            if is_root {
+                return x;
+            }
+            loop {
+                // NOTE: Since `x > sqrt(self)`, `self / x < x`, so this cannot overflow.
+                let y = x.wrapping_add(self.div_const(x)).wrapping_shr(1);
+                if self.is_isqrt_iterate(y) {
+                    return y;
+                }
+                x = y;
+            }
+        }
+
+        // Get the initial estimate for the Newton iterations of `isqrt`, and
+        // if it is the root, which is calculated directly for 64-bit values.
+        #[inline(always)]
+        const fn isqrt_seed(self) -> (Self, bool) {
+            let bits = Self::BITS - self.leading_zeros();
+            if bits <= u64::BITS {
+                return (Self::from_u64($crate::math::root::isqrt_u64(self.as_u64())), true);
+            }
+
+            // Seed with `(isqrt(m) + 1) * 2^s`, where `m` is the high 64 bits of
+            // the value shifted by an even `2s`. Since `self < (m + 1) * 2^2s`,
+            // this is always larger than the root and accurate to ~32 bits.
+            let shift = (bits - u64::BITS + 1) / 2;
+            let high = self.wrapping_shr(2 * shift).as_u64();
+            let seed = $crate::math::root::isqrt_u64(high) + 1;
+            (Self::from_u64(seed).wrapping_shl(shift), false)
+        }
+
+        // If the Newton iterate `y` for `isqrt` is the root. The iterates are
+        // never below the root, so the first with `y^2 <= self` is the root.
+        // Checking this with a square avoids the final division to detect
+        // convergence.
+        #[inline(always)]
+        const fn is_isqrt_iterate(self, y: Self) -> bool {
+            let (lo, hi) = y.widening_square();
+            hi.eq_const(Self::from_u8(0)) && lo.le_const(self)
+        }
+
+        /// Returns the `n`th root of the number, rounded down.
+        ///
+        /// This is seeded from the high 64 bits of the value, and then
+        /// refined using Newton's method, which converges from above to
+        /// the root.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `n` is zero.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn iroot(self, n: u32) -> Self {
+            match self.checked_iroot(n) {
+                Some(root) => root,
+                None => core::panic!("degree of integer root must be positive"),
+            }
+        }
+
+        /// Returns the cube root of the number, rounded down.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn icbrt(self) -> Self {
+            self.iroot(3)
+        }
+
+        /// Returns the base and largest exponent if the number is a perfect
+        /// power, that is, `self == base.pow(exponent)` with `exponent >= 2`.
+        ///
+        /// Returns `None` if the number is not a perfect power. `0` and `1`
+        /// are a perfect power of every exponent, and return an exponent of 2.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn is_perfect_power(self) -> Option<(Self, u32)> {
+            if self.le_const(Self::from_u8(1)) {
+                return Some((self, 2));
+            }
+
+            // The smallest exponent found must be prime, and every exponent
+            // must divide the multiplicity of 2 in the value, if any. The
+            // root may also be a perfect power, which gives the largest one.
+            let zeros = self.trailing_zeros();
+            let max = self.ilog2();
+            let mut n = 2;
+            while n <= max {
+                let maybe_prime = n == 2 || n % 2 != 0;
+                if maybe_prime && (zeros == 0 || zeros % n == 0) {
+                    let root = self.iroot(n);
+                    if root.wrapping_pow(n).eq_const(self) {
+                        return match root.is_perfect_power() {
+                            Some((base, exp)) => Some((base, exp * n)),
+                            None => Some((root, n)),
+                        };
+                    }
+                }
+                n += 1;
+            }
+            None
+        }
+
+        /// Computes the greatest common divisor of `self` and `rhs`.
+        ///
+        /// This uses the binary GCD algorithm, which only requires shifts
+        /// and subtractions. The GCD of `0` and `x` is `x`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn gcd(self, rhs: Self) -> Self {
+            let zero = Self::from_u8(0);
+            if self.eq_const(zero) {
+                return rhs;
+            } else if rhs.eq_const(zero) {
+                return self;
+            }
+
+            // NOTE: The common factors of 2 are removed first and restored
+            // at the end, and each subtraction of 2 odd values is even.
+            let shift = self.bitor_const(rhs).trailing_zeros();
+            let mut a = self.wrapping_shr(self.trailing_zeros());
+            let mut b = rhs;
+            loop {
+                b = b.wrapping_shr(b.trailing_zeros());
+                if a.gt_const(b) {
+                    let t = a;
+                    a = b;
+                    b = t;
+                }
+                b = b.wrapping_sub(a);
+                if b.eq_const(zero) {
+                    return a.wrapping_shl(shift);
+                }
+            }
+        }
+
+        /// Computes the least common multiple of `self` and `rhs`.
+        ///
+        /// The LCM of `0` and `x` is `0`.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if the result overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn lcm(self, rhs: Self) -> Self {
+            match self.checked_lcm(rhs) {
+                Some(value) => value,
+                None => core::panic!("attempt to multiply with overflow"),
+            }
+        }
+
+        /// Computes the greatest common divisor of `self` and `rhs`, and the
+        /// Bezout coefficients `x` and `y`, such that `self * x + rhs * y == gcd`.
+        ///
+        /// This uses the extended Euclidean algorithm, and the coefficients
+        /// are the minimal pair, with `|x| <= max(rhs / (2 * gcd), 1)` and
+        /// `|y| <= max(self / (2 * gcd), 1)`, so they always fit in the signed
+        /// type. If `rhs` is `0`, this returns `(self, 1, 0)`.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn extended_gcd(self, rhs: Self) -> (Self, $s_t, $s_t) {
+            // NOTE: The coefficients alternate in sign, with `x` positive for
+            // an even number of steps and `y` positive for an odd number, so
+            // we track their magnitudes with `|c[i+1]| = |c[i-1]| + q * |c[i]|`.
+            let zero = Self::from_u8(0);
+            let mut r0 = self;
+            let mut r1 = rhs;
+            let mut x0 = Self::from_u8(1);
+            let mut x1 = zero;
+            let mut y0 = zero;
+            let mut y1 = Self::from_u8(1);
+            let mut odd = false;
+            while !r1.eq_const(zero) {
+                let (q, r) = r0.wrapping_div_rem(r1);
+                (r0, r1) = (r1, r);
+                (x0, x1) = (x1, x0.wrapping_add(q.wrapping_mul(x1)));
+                (y0, y1) = (y1, y0.wrapping_add(q.wrapping_mul(y1)));
+                odd = !odd;
+            }
+
+            let x = x0.as_signed();
+            let y = y0.as_signed();
+            match odd {
+                true => (r0, x.wrapping_neg(), y),
+                false => (r0, x, y.wrapping_neg()),
+            }
+        }
 
         /// Computes the absolute difference between `self` and `other`.
         ///
@@ -289,6 +638,22 @@ macro_rules! define {
             (Self::from_ne_limbs(lo), Self::from_ne_limbs(hi))
         }
 
+        /// Calculates the complete square `self * self` without the
+        /// possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order
+        /// (overflow) bits of the result as two separate values, in that
+        /// order. This requires about half the limb multiplications of
+        /// [`widening_mul`].
+        ///
+        /// [`widening_mul`]: Self::widening_mul
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn widening_square(self) -> (Self, Self) {
+            let (lo, hi) = $crate::math::mul::widening_square(&self.to_ne_limbs());
+            (Self::from_ne_limbs(lo), Self::from_ne_limbs(hi))
+        }
+
         /// Calculates the "full multiplication" `self * rhs + carry`
         /// without the possibility to overflow.
         ///
@@ -314,6 +679,63 @@ macro_rules! define {
 
             (lo, hi)
         }
+
+        /// Calculates `self * b / c`, rounded down.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div(self, b: Self, c: Self) -> Self {
+            self.mul_div_rem(b, c).0
+        }
+
+        /// Calculates the quotient and remainder of `self * b / c`.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div_rem(self, b: Self, c: Self) -> (Self, Self) {
+            assert!(!c.eq_const(Self::from_u8(0)), "attempt to divide by zero");
+            match self.checked_mul_div_rem(b, c) {
+                Some(v) => v,
+                None => core::panic!("attempt to multiply with overflow"),
+            }
+        }
+
+        /// Calculates `self * b / c`, rounded up.
+        ///
+        /// The product is calculated at double width, so this only fails
+        /// if the final quotient overflows, even if `self * b` would.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `c` is zero or if the quotient
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn mul_div_ceil(self, b: Self, c: Self) -> Self {
+            let (quo, rem) = self.mul_div_rem(b, c);
+            if rem.eq_const(Self::from_u8(0)) {
+                quo
+            } else {
+                match quo.checked_add(Self::from_u8(1)) {
+                    Some(v) => v,
+                    None => core::panic!("attempt to multiply with overflow"),
+                }
+            }
+        }
     };
 }
 
@@ -107,6 +107,18 @@ macro_rules! define {
             (Self::from_ne_limbs(limbs), overflowed)
         }
 
+        /// Calculates the square of `self`.
+        ///
+        /// Returns a tuple of the square along with a boolean indicating
+        /// whether an arithmetic overflow would occur. If an overflow would
+        /// have occurred then the wrapped value is returned.
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn overflowing_square(self) -> (Self, bool) {
+            let (lo, hi) = self.widening_square();
+            (lo, !hi.eq_const(Self::from_u8(0)))
+        }
+
         /// Calculates the divisor when `self` is divided by `rhs`.
         ///
         /// Returns a tuple of the divisor along with a boolean indicating
@@ -0,0 +1,375 @@
+//! Primality testing for unsigned integers.
+//!
+//! This uses the Baillie-PSW test, which combines a strong probable
+//! prime test to base 2 with a strong Lucas probable prime test. There
+//! are no known composites which pass both tests, and it has been
+//! verified to have no counterexamples below `2^64`.
+
+#[rustfmt::skip]
+macro_rules! define {
+    (bits => $bits:expr $(,)?) => {
+        /// Returns `true` if the number is prime.
+        ///
+        /// This uses trial division by the primes less than 256, followed by
+        /// the Baillie-PSW test, which is a strong probable prime test to
+        /// base 2 and a strong Lucas probable prime test. This is always
+        /// correct for values less than `2^64`, and there are no known
+        /// composites of any size which pass the test. Use
+        /// [`is_probable_prime`] for additional Miller-Rabin rounds.
+        ///
+        /// [`is_probable_prime`]: Self::is_probable_prime
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn is_prime(self) -> bool {
+            self.is_probable_prime(0)
+        }
+
+        /// Returns `true` if the number is prime, using the Baillie-PSW test
+        /// with up to `rounds` additional Miller-Rabin rounds.
+        ///
+        /// The additional strong probable prime tests use the odd primes
+        /// `3, 5, 7, 11, ...` as bases, up to a maximum of 53 rounds, and are
+        /// skipped for values less than `2^64`, since the Baillie-PSW test is
+        /// always correct for them.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn is_probable_prime(self, rounds: u32) -> bool {
+            if self.lt_const(Self::from_u8(2)) {
+                return false;
+            } else if self.is_even() {
+                return self.eq_const(Self::from_u8(2));
+            }
+
+            // NOTE: Any composite less than `257^2` has a prime factor less
+            // than 256, so these are fully handled by trial division.
+            let mut i = 0;
+            while i < $crate::math::prime::SMALL_PRIMES.len() {
+                let p = $crate::math::prime::SMALL_PRIMES[i] as u32;
+                if self.rem_ulimb(p as $crate::ULimb) == 0 {
+                    return self.eq_const(Self::from_u32(p));
+                }
+                i += 1;
+            }
+            if self.lt_const(Self::from_u32(257 * 257)) {
+                return true;
+            }
+
+            let ctx = $crate::Montgomery::<Self>::new(self);
+            if !self.is_strong_probable_prime(&ctx, Self::from_u8(2)) || !self.is_strong_lucas_probable_prime(&ctx) {
+                return false;
+            }
+
+            let small = Self::BITS - self.leading_zeros() <= 64;
+            let mut i = 0;
+            while !small && i < rounds as usize && i < $crate::math::prime::SMALL_PRIMES.len() {
+                let base = Self::from_u8($crate::math::prime::SMALL_PRIMES[i]);
+                if !self.is_strong_probable_prime(&ctx, base) {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        /// Returns the smallest prime greater than `self`, or `None` if it
+        /// overflows.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn next_prime(self) -> Option<Self> {
+            if self.lt_const(Self::from_u8(2)) {
+                return Some(Self::from_u8(2));
+            }
+
+            let step = Self::from_u8(2);
+            let mut n = match self.is_even() {
+                true => self.checked_add(Self::from_u8(1))?,
+                false => self.checked_add(step)?,
+            };
+            while !n.is_prime() {
+                n = n.checked_add(step)?;
+            }
+            Some(n)
+        }
+
+        /// Returns the largest prime less than `self`, or `None` if `self`
+        /// is less than or equal to 2.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn prev_prime(self) -> Option<Self> {
+            if self.le_const(Self::from_u8(2)) {
+                return None;
+            } else if self.eq_const(Self::from_u8(3)) {
+                return Some(Self::from_u8(2));
+            }
+
+            // NOTE: 3 is prime, so this always terminates before underflowing.
+            let step = Self::from_u8(2);
+            let mut n = match self.is_even() {
+                true => self.wrapping_sub(Self::from_u8(1)),
+                false => self.wrapping_sub(step),
+            };
+            while !n.is_prime() {
+                n = n.wrapping_sub(step);
+            }
+            Some(n)
+        }
+
+        /// Returns the prime factorization of the number, as `(prime, exponent)`
+        /// pairs sorted by the prime.
+        ///
+        /// This uses trial division by the primes less than 256, and then
+        /// Brent's variant of Pollard's rho algorithm to split any composite
+        /// cofactors, which are identified by the Baillie-PSW test. The time
+        /// for Pollard's rho algorithm is proportional to the square root of
+        /// the smallest prime factor, so this can be extremely slow if the
+        /// number has multiple prime factors larger than about `2^80`. The
+        /// factorization of `1` is empty.
+        ///
+        /// # Panics
+        ///
+        /// This function will panic if `self` is zero.
+        #[inline]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub fn factor(self) -> $crate::Factors<Self, { $bits / 4 }> {
+            assert!(!self.eq_const(Self::from_u8(0)), "attempt to factor zero");
+            let mut factors = $crate::Factors::new(Self::from_u8(0));
+            let zeros = self.trailing_zeros();
+            if zeros != 0 {
+                factors.insert(Self::from_u8(2), zeros);
+            }
+
+            let mut n = self.wrapping_shr(zeros);
+            for &prime in $crate::math::prime::SMALL_PRIMES.iter() {
+                let mut exp = 0;
+                loop {
+                    let (quotient, rem) = n.div_rem_ulimb(prime as $crate::ULimb);
+                    if rem != 0 {
+                        break;
+                    }
+                    n = quotient;
+                    exp += 1;
+                }
+                if exp != 0 {
+                    factors.insert(Self::from_u8(prime), exp);
+                }
+            }
+
+            n.factor_odd(&mut factors, 1);
+            factors
+        }
+
+        /// Add the factorization of the odd number `self^exp`, which has no
+        /// prime factors less than 256, to the factors.
+        #[inline]
+        fn factor_odd(self, factors: &mut $crate::Factors<Self, { $bits / 4 }>, exp: u32) {
+            if self.eq_const(Self::from_u8(1)) {
+                return;
+            } else if self.is_prime() {
+                return factors.insert(self, exp);
+            }
+
+            // NOTE: Pollard's rho algorithm is slow for the powers of large
+            // primes, so we find the root of any perfect powers first.
+            if let Some((root, power)) = self.is_perfect_power() {
+                return root.factor_odd(factors, exp * power);
+            }
+
+            let mut c = 1;
+            let divisor = loop {
+                let divisor = self.pollard_brent(c);
+                if !divisor.eq_const(self) {
+                    break divisor;
+                }
+                c += 1;
+            };
+            divisor.factor_odd(factors, exp);
+            self.wrapping_div(divisor).factor_odd(factors, exp);
+        }
+
+        /// Find a non-trivial divisor of the odd composite `self` using Brent's
+        /// variant of Pollard's rho algorithm, with `f(x) = x^2 + c`.
+        ///
+        /// This may return `self` if the cycle is found without a divisor,
+        /// and must then be retried with a different `c`.
+        #[inline]
+        fn pollard_brent(self, c: u32) -> Self {
+            const BATCH: usize = 128;
+
+            // NOTE: This iterates in the Montgomery form, which is still a
+            // pseudo-random map, and `gcd(x * R - y * R, n) == gcd(x - y, n)`
+            // since `R` is coprime to `n`.
+            let ctx = $crate::Montgomery::<Self>::new(self);
+            let c = ctx.to_mont(Self::from_u32(c));
+            let f = |x: Self| ctx.square(x).add_mod(c, self);
+            let one = Self::from_u8(1);
+            let mut x;
+            let mut y = ctx.to_mont(Self::from_u8(2));
+            let mut ys = y;
+            let mut q = ctx.one();
+            let mut g = one;
+            let mut r = 1;
+            loop {
+                x = y;
+                for _ in 0..r {
+                    y = f(y);
+                }
+                // accumulate the differences in batches, to reduce the number
+                // of GCD calculations.
+                let mut k = 0;
+                while k < r && g.eq_const(one) {
+                    ys = y;
+                    for _ in 0..BATCH.min(r - k) {
+                        y = f(y);
+                        q = ctx.mul(q, x.abs_diff(y));
+                    }
+                    g = q.gcd(self);
+                    k += BATCH;
+                }
+                if !g.eq_const(one) {
+                    break;
+                }
+                r *= 2;
+            }
+
+            // if the batch overshot the cycle, backtrack one step at a time.
+            if g.eq_const(self) {
+                loop {
+                    ys = f(ys);
+                    g = x.abs_diff(ys).gcd(self);
+                    if !g.eq_const(one) {
+                        break;
+                    }
+                }
+            }
+            g
+        }
+
+        /// Returns `true` if the odd number `self > base` is a strong probable
+        /// prime to `base`, which is the Miller-Rabin test.
+        ///
+        /// For `self - 1 == d * 2^s` with `d` odd, this checks if
+        /// `base^d == 1` or `base^(d * 2^r) == -1` for some `0 <= r < s`.
+        #[inline]
+        fn is_strong_probable_prime(self, ctx: &$crate::Montgomery<Self>, base: Self) -> bool {
+            let n_1 = self.wrapping_sub(Self::from_u8(1));
+            let s = n_1.trailing_zeros();
+            let d = n_1.wrapping_shr(s);
+
+            // NOTE: In the Montgomery form, `-1 == N - R mod N`.
+            let one = ctx.one();
+            let minus_one = self.wrapping_sub(one);
+            let mut x = ctx.pow(ctx.to_mont(base), d);
+            if x.eq_const(one) || x.eq_const(minus_one) {
+                return true;
+            }
+            let mut r = 1;
+            while r < s {
+                x = ctx.square(x);
+                if x.eq_const(minus_one) {
+                    return true;
+                } else if x.eq_const(one) {
+                    return false;
+                }
+                r += 1;
+            }
+            false
+        }
+
+        /// Returns `true` if the odd number `self`, which is not divisible by
+        /// any small primes, is a strong Lucas probable prime.
+        ///
+        /// This uses the parameters from Selfridge's method A, where `D` is
+        /// the first of `5, -7, 9, -11, ...` with the Jacobi symbol
+        /// `(D / n) == -1`, `P = 1` and `Q = (1 - D) / 4`. For
+        /// `n + 1 == d * 2^s` with `d` odd, this checks if `U_d == 0` or
+        /// `V_(d * 2^r) == 0` for some `0 <= r < s`.
+        #[inline]
+        fn is_strong_lucas_probable_prime(self, ctx: &$crate::Montgomery<Self>) -> bool {
+            // NOTE: `D == 1 mod 4`, so by quadratic reciprocity,
+            // `(D / n) == (n / |D|) == (n mod |D| / |D|)`. If `n` is a perfect
+            // square, then no such `D` exists, so we check that after a few
+            // attempts, which rarely occurs for other values.
+            let mut d_abs = 5u32;
+            let mut d_negative = false;
+            loop {
+                let j = $crate::math::prime::jacobi_u64(self.rem_ulimb(d_abs as $crate::ULimb) as u64, d_abs as u64);
+                if j == -1 {
+                    break;
+                } else if j == 0 && !self.eq_const(Self::from_u32(d_abs)) {
+                    return false;
+                } else if d_abs == 13 {
+                    let root = self.isqrt();
+                    if root.wrapping_mul(root).eq_const(self) {
+                        return false;
+                    }
+                }
+                d_abs += 2;
+                d_negative = !d_negative;
+            }
+
+            // convert the signed parameters into the Montgomery form.
+            let zero = Self::from_u8(0);
+            let to_mont = |abs: u32, negative: bool| {
+                let value = ctx.to_mont(Self::from_u32(abs));
+                match negative {
+                    true => zero.sub_mod(value, self),
+                    false => value,
+                }
+            };
+            let d = to_mont(d_abs, d_negative);
+            // NOTE: `Q = (1 - D) / 4`, which is `-(|D| - 1) / 4` for `D > 0`.
+            let q = match d_negative {
+                true => to_mont((d_abs + 1) / 4, false),
+                false => to_mont((d_abs - 1) / 4, true),
+            };
+            // NOTE: `x / 2 mod n` is `(x + n) / 2` for odd `x`, which can
+            // overflow, so we use `floor(x / 2) + floor(n / 2) + 1`.
+            let half = |x: Self| match x.is_odd() {
+                true => x.wrapping_shr(1).wrapping_add(self.wrapping_shr(1)).wrapping_add(Self::from_u8(1)),
+                false => x.wrapping_shr(1),
+            };
+
+            // NOTE: `n` cannot be `MAX`, since `2^BITS - 1` is always divisible
+            // by 3 for our even number of bits, so this cannot overflow.
+            let n_1 = self.wrapping_add(Self::from_u8(1));
+            let s = n_1.trailing_zeros();
+            let k = n_1.wrapping_shr(s);
+
+            // calculate `U_k`, `V_k` and `Q^k` from the most-significant bits,
+            // using `U_2k = U_k * V_k`, `V_2k = V_k^2 - 2 * Q^k` and
+            // `U_(k+1) = (U_k + V_k) / 2`, `V_(k+1) = (D * U_k + V_k) / 2`.
+            let mut u = ctx.one();
+            let mut v = ctx.one();
+            let mut qk = q;
+            let mut bit = Self::BITS - k.leading_zeros() - 1;
+            while bit > 0 {
+                bit -= 1;
+                u = ctx.mul(u, v);
+                v = ctx.square(v).sub_mod(qk.add_mod(qk, self), self);
+                qk = ctx.square(qk);
+                if k.wrapping_shr(bit).is_odd() {
+                    let next_u = half(u.add_mod(v, self));
+                    v = half(ctx.mul(d, u).add_mod(v, self));
+                    u = next_u;
+                    qk = ctx.mul(qk, q);
+                }
+            }
+
+            if u.eq_const(zero) || v.eq_const(zero) {
+                return true;
+            }
+            let mut r = 1;
+            while r < s {
+                v = ctx.square(v).sub_mod(qk.add_mod(qk, self), self);
+                if v.eq_const(zero) {
+                    return true;
+                }
+                qk = ctx.square(qk);
+                r += 1;
+            }
+            false
+        }
+    };
+}
+
+pub(crate) use define;
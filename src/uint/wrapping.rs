@@ -216,6 +216,21 @@ macro_rules! define {
             Self::from_ne_limbs(limbs)
         }
 
+        /// Wrapping (modular) squaring. Computes `self * self`, wrapping
+        /// around at the boundary of the type.
+        ///
+        /// This only calculates each cross product of the limbs once, so
+        /// it requires about half the limb multiplications of
+        /// [`wrapping_mul`].
+        ///
+        /// [`wrapping_mul`]: Self::wrapping_mul
+        #[inline(always)]
+        #[must_use = $crate::shared::docs::must_use_copy_doc!()]
+        pub const fn wrapping_square(self) -> Self {
+            let limbs = $crate::math::mul::wrapping_square(&self.to_ne_limbs());
+            Self::from_ne_limbs(limbs)
+        }
+
         /// Get the quotient and remainder of our big integer divided
         /// by a signed limb, wrapping on overflow.
         ///
@@ -82,6 +82,19 @@ macro_rules! limb_function {
     (mn $name:ident, $bit64:ident, $bit32:ident, $t:ty, ret => $ret:ty) => {
         limb_function!(mn $name, $bit64, $bit32, $t, $t, ret => $ret);
     };
+
+    (unary $name:ident, $bit64:ident, $bit32:ident, $t:ty, ret => $ret:ty) => {
+        #[inline(always)]
+        pub const fn $name<const N: usize>(x: $t) -> $ret {
+            #[cfg(all(not(feature = "limb32"), target_pointer_width = "64"))]
+            let result = $bit64(x);
+
+            #[cfg(any(feature = "limb32", not(target_pointer_width = "64")))]
+            let result = $bit32(x);
+
+            result
+        }
+    };
 }
 
 /// No-op, this is meant for 32-bit ISAs.
@@ -163,3 +176,45 @@ pub(crate) const fn i128_to_limb_n<const N: usize>(value: i128) -> [u32; N] {
 
     result
 }
+
+/// Decompose a finite [`f64`] into its sign, binary exponent and integral
+/// significand, such that `value == (-1)^sign * significand * 2^exp`.
+#[inline]
+pub(crate) fn f64_to_parts(value: f64) -> (bool, i32, u64) {
+    debug_assert!(value.is_finite());
+    let bits = value.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    if biased == 0 {
+        // denormal, which has an implicit exponent of `1 - bias`
+        (negative, -1074, fraction)
+    } else {
+        (negative, biased - 1075, fraction | (1 << 52))
+    }
+}
+
+/// Decompose a finite [`f32`] into its sign, binary exponent and integral
+/// significand, such that `value == (-1)^sign * significand * 2^exp`.
+#[inline]
+pub(crate) fn f32_to_parts(value: f32) -> (bool, i32, u64) {
+    debug_assert!(value.is_finite());
+    let bits = value.to_bits();
+    let negative = bits >> 31 != 0;
+    let biased = ((bits >> 23) & 0xFF) as i32;
+    let fraction = (bits & ((1 << 23) - 1)) as u64;
+    if biased == 0 {
+        // denormal, which has an implicit exponent of `1 - bias`
+        (negative, -149, fraction)
+    } else {
+        (negative, biased - 150, fraction | (1 << 23))
+    }
+}
+
+/// Create the power-of-two `2^exp` as an [`f64`], which is exact for
+/// all normal exponents.
+#[inline]
+pub(crate) fn f64_pow2(exp: u32) -> f64 {
+    debug_assert!(exp <= 1023);
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}